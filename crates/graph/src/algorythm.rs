@@ -1,4 +1,10 @@
 pub mod bellman_ford;
+pub mod bfs;
+pub mod biconnected_components;
 pub mod dijkstra;
+pub mod floyd_warshall;
 pub mod kruskal;
 pub mod lowest_common_ancestor;
+pub mod lowlink;
+pub mod strongly_connected_components;
+pub mod topological_sort;
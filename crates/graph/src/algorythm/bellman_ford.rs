@@ -1,3 +1,4 @@
+use crate::Graph;
 use num_traits::Zero;
 
 #[derive(Debug, Clone)]
@@ -5,59 +6,59 @@ pub struct BellmanFordError;
 
 type BellmanFordResult<T> = Result<T, BellmanFordError>;
 
-pub fn bellman_ford<W: Copy + Ord + Zero>(
-    n: usize,
-    edges: &[(usize, usize, W)],
-    start: usize,
-) -> BellmanFordResult<Vec<Option<W>>> {
-    let mut dist = vec![None; n];
-    dist[start] = Some(W::zero());
-
-    for _ in 1..n {
-        for &(u, v, w) in edges.iter() {
-            if dist[u].is_some() && dist[v].is_none() || dist[v].unwrap() > dist[u].unwrap() + w {
-                dist[v] = Some(dist[u].unwrap() + w);
-            }
-        }
-    }
-
-    if edges.iter().all(|&(u, v, w)| match (dist[u], dist[v]) {
-        (None, None) => true,
-        (None, Some(_)) => true,
-        (Some(_), None) => false,
-        (Some(x), Some(y)) => x + w >= y,
-    }) {
-        Ok(dist)
-    } else {
-        Err(BellmanFordError)
-    }
+/// Bellman-Ford algorythm
+///
+/// Returns the distances from `start`,
+/// or an error if a negative cycle is reachable from `start`
+///
+/// See https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm
+pub fn bellman_ford<G>(g: &G, start: usize) -> BellmanFordResult<Vec<Option<G::Weight>>>
+where
+    G: Graph,
+    G::Weight: Ord + Zero,
+{
+    bellman_ford_with_path_hint(g, start).map(|(dist, _)| dist)
 }
 
+/// Bellman-Ford algorythm with path
+///
+/// Returns a pair of (dist, prev),
+/// to restore the shortest path from `start` to `x`,
+/// call `x = prev[x]` repeatedly
 #[allow(clippy::type_complexity)]
-pub fn bellman_ford_with_path_hint<W: Copy + Ord + Zero>(
-    n: usize,
-    edges: &[(usize, usize, W)],
+pub fn bellman_ford_with_path_hint<G>(
+    g: &G,
     start: usize,
-) -> BellmanFordResult<(Vec<Option<W>>, Vec<Option<usize>>)> {
+) -> BellmanFordResult<(Vec<Option<G::Weight>>, Vec<Option<usize>>)>
+where
+    G: Graph,
+    G::Weight: Ord + Zero,
+{
+    let n = g.len();
     let mut dist = vec![None; n];
-    dist[start] = Some(W::zero());
+    dist[start] = Some(G::Weight::zero());
 
     let mut prev = vec![None; n];
 
     for _ in 1..n {
-        for &(u, v, w) in edges.iter() {
-            if dist[u].is_some() && dist[v].is_none() || dist[v].unwrap() > dist[u].unwrap() + w {
-                dist[v] = Some(dist[u].unwrap() + w);
-                prev[v] = Some(u);
+        for u in 0..n {
+            let Some(du) = dist[u] else {
+                continue;
+            };
+            for (v, w) in g.edges(u) {
+                if dist[v].is_none_or(|dv| dv > du + w) {
+                    dist[v] = Some(du + w);
+                    prev[v] = Some(u);
+                }
             }
         }
     }
 
-    if edges.iter().all(|&(u, v, w)| match (dist[u], dist[v]) {
-        (None, None) => true,
-        (None, Some(_)) => true,
-        (Some(_), None) => false,
-        (Some(x), Some(y)) => x + w >= y,
+    if (0..n).all(|u| match dist[u] {
+        None => true,
+        Some(du) => g
+            .edges(u)
+            .all(|(v, w)| dist[v].is_some_and(|dv| du + w >= dv)),
     }) {
         Ok((dist, prev))
     } else {
@@ -67,10 +68,22 @@ pub fn bellman_ford_with_path_hint<W: Copy + Ord + Zero>(
 
 #[test]
 fn bellman_ford_test() {
-    let negetive_cycle: [(usize, usize, i32); 3] = [(0, 1, 1), (1, 2, 1), (2, 0, -100)];
-    assert!(bellman_ford(3, &negetive_cycle, 0).is_err());
+    use crate::DWLGraph;
+
+    let negetive_cycle = DWLGraph::from_edges(3, &[(0, 1, 1), (1, 2, 1), (2, 0, -100)]);
+    assert!(bellman_ford(&negetive_cycle, 0).is_err());
 
-    let edges: [(usize, usize, i32); 5] = [(0, 1, 1), (0, 2, 3), (1, 2, -1), (1, 3, 3), (2, 3, 1)];
-    let dist = bellman_ford(4, &edges, 0).unwrap();
-    dbg!(dist);
+    let g = DWLGraph::from_edges(
+        5,
+        &[
+            (0, 1, 1),
+            (0, 2, 3),
+            (1, 2, -1),
+            (1, 3, 3),
+            (2, 3, 1),
+            (4, 0, -5),
+        ],
+    );
+    let dist = bellman_ford(&g, 0).unwrap();
+    assert_eq!(dist, vec![Some(0), Some(1), Some(0), Some(1), None]);
 }
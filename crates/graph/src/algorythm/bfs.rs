@@ -0,0 +1,27 @@
+use crate::Graph;
+use std::collections::VecDeque;
+
+/// Breadth-first search
+///
+/// Returns the number of edges on the shortest path from `start` to each node,
+/// ignoring the weights
+///
+/// See https://en.wikipedia.org/wiki/Breadth-first_search
+pub fn bfs<G: Graph>(g: &G, start: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; g.len()];
+    dist[start] = Some(0);
+    let mut q = VecDeque::new();
+    q.push_back(start);
+
+    while let Some(cur) = q.pop_front() {
+        let d = dist[cur].map(|x| x + 1);
+        for next in g.neighbors(cur) {
+            if dist[next].is_none() {
+                dist[next] = d;
+                q.push_back(next);
+            }
+        }
+    }
+
+    dist
+}
@@ -0,0 +1,294 @@
+use crate::{algorythm::lowlink::Lowlink, Graph, UULGraph};
+
+/// Two-edge-connected components
+///
+/// Decomposes an undirected graph into maximal subgraphs without bridges,
+/// which are obtained by removing all the bridges.
+/// The components are numbered in the preorder of the DFS in [`Lowlink`].
+///
+/// See https://en.wikipedia.org/wiki/Bridge_(graph_theory)#Bridge-finding_with_chain_decompositions
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::biconnected_components::TwoEdgeConnectedComponents, UULGraph};
+///
+/// let g = UULGraph::from_edges(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (3, 5), (3, 5)]);
+/// let tecc = TwoEdgeConnectedComponents::new(&g);
+/// assert_eq!(tecc.count(), 3);
+/// assert_eq!(tecc.groups(), vec![vec![0, 1, 2], vec![3, 5], vec![4]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TwoEdgeConnectedComponents {
+    count: usize,
+    ids: Vec<usize>,
+}
+
+impl TwoEdgeConnectedComponents {
+    pub fn new<G: Graph>(g: &G) -> Self {
+        Self::from_lowlink(&Lowlink::new(g))
+    }
+
+    pub fn from_lowlink(lowlink: &Lowlink) -> Self {
+        let mut ids = vec![usize::MAX; lowlink.preorder().len()];
+        let mut count = 0;
+        for &v in lowlink.preorder() {
+            ids[v] = match lowlink.parent(v) {
+                Some(p) if !lowlink.is_bridge(p, v) => ids[p],
+                _ => {
+                    count += 1;
+                    count - 1
+                }
+            };
+        }
+        Self { count, ids }
+    }
+
+    /// Returns the number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component id of `v`
+    pub fn id(&self, v: usize) -> usize {
+        self.ids[v]
+    }
+
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    /// Returns the nodes of each component
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = vec![vec![]; self.count];
+        for (v, &id) in self.ids.iter().enumerate() {
+            groups[id].push(v);
+        }
+        groups
+    }
+
+    /// Returns the bridge tree of `g`, a forest whose nodes are the components
+    /// and whose edges are the bridges
+    pub fn bridge_tree<G: Graph>(&self, g: &G) -> UULGraph {
+        let edges = (0..g.len())
+            .flat_map(|u| g.neighbors(u).map(move |v| (self.ids[u], self.ids[v])))
+            .filter(|&(a, b)| a < b)
+            .collect::<Vec<_>>();
+        UULGraph::from_edges(self.count, &edges)
+    }
+}
+
+/// Biconnected components
+///
+/// Decomposes an undirected graph into blocks,
+/// maximal subgraphs without articulation points of their own.
+/// Every edge belongs to exactly one block, while articulation points belong to several.
+/// An isolated node forms a block by itself, and self-loops are ignored.
+/// The blocks are numbered in the preorder of the DFS in [`Lowlink`].
+///
+/// See https://en.wikipedia.org/wiki/Biconnected_component
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::biconnected_components::BiconnectedComponents, UULGraph};
+///
+/// let g = UULGraph::from_edges(7, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (3, 5), (3, 5)]);
+/// let bcc = BiconnectedComponents::new(&g);
+/// assert_eq!(bcc.count(), 5);
+/// assert_eq!(bcc.groups(), &[vec![0, 1, 2], vec![2, 3], vec![3, 4], vec![3, 5], vec![6]]);
+/// assert_eq!(bcc.edge_groups()[3], vec![(5, 3), (5, 3)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BiconnectedComponents {
+    len: usize,
+    groups: Vec<Vec<usize>>,
+    edge_groups: Vec<Vec<(usize, usize)>>,
+}
+
+impl BiconnectedComponents {
+    pub fn new<G: Graph>(g: &G) -> Self {
+        Self::from_lowlink(g, &Lowlink::new(g))
+    }
+
+    /// `lowlink` must be built from `g`
+    pub fn from_lowlink<G: Graph>(g: &G, lowlink: &Lowlink) -> Self {
+        let n = g.len();
+        let mut has_child = vec![false; n];
+        for v in 0..n {
+            if let Some(p) = lowlink.parent(v) {
+                has_child[p] = true;
+            }
+        }
+
+        // `block[v]` is the block containing the edge from the parent of `v`,
+        // or the block of `v` itself if it is isolated
+        let mut block = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for &v in lowlink.preorder() {
+            match lowlink.parent(v) {
+                Some(p) if lowlink.low(v) < lowlink.order(p) => {
+                    block[v] = block[p];
+                    groups[block[v]].push(v);
+                }
+                Some(p) => {
+                    block[v] = groups.len();
+                    groups.push(vec![p, v]);
+                }
+                None if !has_child[v] => {
+                    block[v] = groups.len();
+                    groups.push(vec![v]);
+                }
+                None => {}
+            }
+        }
+        for group in groups.iter_mut() {
+            group.sort();
+        }
+
+        // Each edge `v - w` is in the same block as the edge from the parent of `v`,
+        // where `w` is visited before `v`
+        let mut edge_groups = vec![vec![]; groups.len()];
+        for v in 0..n {
+            for w in g.neighbors(v) {
+                if lowlink.order(w) < lowlink.order(v) {
+                    edge_groups[block[v]].push((v, w));
+                }
+            }
+        }
+
+        Self {
+            len: n,
+            groups,
+            edge_groups,
+        }
+    }
+
+    /// Returns the number of blocks
+    pub fn count(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns the nodes of each block in ascending order
+    pub fn groups(&self) -> &[Vec<usize>] {
+        &self.groups
+    }
+
+    /// Returns the edges of each block
+    pub fn edge_groups(&self) -> &[Vec<(usize, usize)>] {
+        &self.edge_groups
+    }
+
+    /// Returns the block-cut tree, a forest with `n + count` nodes,
+    /// where the node `v < n` is the node `v` of the original graph
+    /// and the node `n + i` is the `i`-th block.
+    /// Each node is connected to the blocks containing it,
+    /// so the articulation points are the nodes of degree two or more.
+    pub fn block_cut_tree(&self) -> UULGraph {
+        let edges = self
+            .groups
+            .iter()
+            .enumerate()
+            .flat_map(|(i, group)| group.iter().map(move |&v| (v, self.len + i)))
+            .collect::<Vec<_>>();
+        UULGraph::from_edges(self.len + self.count(), &edges)
+    }
+}
+
+#[test]
+fn biconnected_components_test() {
+    use crate::union_find::UnionFind;
+    use itertools::{iproduct, Itertools};
+    use rand::Rng;
+
+    let connected = |n: usize, edges: &[(usize, usize)], a: usize, b: usize| {
+        let mut uf = UnionFind::new(n);
+        for &(u, v) in edges.iter() {
+            uf.unite(u, v);
+        }
+        uf.is_joint(a, b)
+    };
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..300 {
+        let n = rng.gen_range(1..10);
+        let m = rng.gen_range(0..16);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect_vec();
+        let g = UULGraph::from_edges(n, &edges);
+        let lowlink = Lowlink::new(&g);
+
+        // two nodes are two-edge-connected iff no single edge disconnects them
+        let tecc = TwoEdgeConnectedComponents::new(&g);
+        for (a, b) in iproduct!(0..n, 0..n) {
+            let expected = connected(n, &edges, a, b)
+                && (0..m).all(|i| {
+                    let mut rest = edges.clone();
+                    rest.remove(i);
+                    connected(n, &rest, a, b)
+                });
+            assert_eq!(tecc.id(a) == tecc.id(b), expected);
+        }
+        let tree = tecc.bridge_tree(&g);
+        let tree_edges = (0..tree.len())
+            .map(|a| tree.neighbors(a).count())
+            .sum::<usize>()
+            / 2;
+        assert_eq!(tree_edges, lowlink.bridges().len());
+
+        // two nodes share a block iff they are adjacent,
+        // or connected and no other single node disconnects them
+        let bcc = BiconnectedComponents::from_lowlink(&g, &lowlink);
+        for (a, b) in iproduct!(0..n, 0..n).filter(|&(a, b)| a != b) {
+            let expected = edges.contains(&(a, b))
+                || connected(n, &edges, a, b)
+                    && (0..n).filter(|&x| x != a && x != b).all(|x| {
+                        let rest = edges
+                            .iter()
+                            .copied()
+                            .filter(|&(u, v)| u != x && v != x)
+                            .collect_vec();
+                        connected(n, &rest, a, b)
+                    });
+            let shared = bcc
+                .groups()
+                .iter()
+                .any(|group| group.contains(&a) && group.contains(&b));
+            assert_eq!(shared, expected || edges.contains(&(b, a)));
+        }
+
+        // every edge but self-loops belongs to exactly one block
+        let mut all = vec![];
+        for (group, edge_group) in bcc.groups().iter().zip(bcc.edge_groups()) {
+            for &(u, v) in edge_group.iter() {
+                assert!(group.contains(&u) && group.contains(&v));
+                all.push((u.min(v), u.max(v)));
+            }
+            if group.len() > 1 {
+                let nodes = edge_group
+                    .iter()
+                    .flat_map(|&(u, v)| [u, v])
+                    .sorted()
+                    .dedup();
+                assert!(nodes.eq(group.iter().copied()));
+            }
+        }
+        let expected = edges
+            .iter()
+            .filter(|&&(u, v)| u != v)
+            .map(|&(u, v)| (u.min(v), u.max(v)))
+            .sorted()
+            .collect_vec();
+        assert_eq!(all.into_iter().sorted().collect_vec(), expected);
+
+        let tree = bcc.block_cut_tree();
+        assert_eq!(tree.len(), n + bcc.count());
+        for v in 0..n {
+            assert_eq!(
+                tree.neighbors(v).count() >= 2,
+                lowlink.is_articulation_point(v)
+            );
+        }
+    }
+}
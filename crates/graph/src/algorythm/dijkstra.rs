@@ -1,55 +1,52 @@
-use itertools::Itertools;
+use crate::Graph;
 use num_traits::Zero;
 use std::{cmp::Reverse, collections::BinaryHeap};
 
-pub fn dijkstra<W: Copy + Ord + Zero>(g: &[Vec<(usize, W)>], start: usize) -> Vec<Option<W>> {
-    let mut dist = vec![None; g.len()];
-    dist[start] = Some(W::zero());
-
-    let mut heap = BinaryHeap::new();
-    heap.push((Reverse(Some(W::zero())), start));
-
-    while let Some((Reverse(Some(d)), cur)) = heap.pop() {
-        if Some(d) != dist[cur] {
-            continue;
-        }
-        for &(next, weight) in g[cur]
-            .iter()
-            .filter(|&&(x, w)| dist[x].is_none() || dist[x].unwrap() > d + w)
-            .collect_vec()
-        {
-            dist[next] = Some(d + weight);
-            heap.push((Reverse(Some(d + weight)), next));
-        }
-    }
-
-    dist
+/// Dijkstra algorythm
+///
+/// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+pub fn dijkstra<G>(g: &G, start: usize) -> Vec<Option<G::Weight>>
+where
+    G: Graph,
+    G::Weight: Ord + Zero,
+{
+    dijkstra_with_path_hint(g, start).0
 }
 
-pub fn dijkstra_with_path_hint<W: Copy + Ord + Zero>(
-    g: &[Vec<(usize, W)>],
+/// Dijkstra algorythm with path
+///
+/// Returns a pair of (dist, prev),
+/// to restore the shortest path from `start` to `x`,
+/// call `x = prev[x]` repeatedly
+///
+/// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+#[allow(clippy::type_complexity)]
+pub fn dijkstra_with_path_hint<G>(
+    g: &G,
     start: usize,
-) -> (Vec<Option<W>>, Vec<Option<usize>>) {
-    let mut dist = vec![Option::None; g.len()];
-    dist[start] = Some(W::zero());
+) -> (Vec<Option<G::Weight>>, Vec<Option<usize>>)
+where
+    G: Graph,
+    G::Weight: Ord + Zero,
+{
+    let mut dist = vec![None; g.len()];
+    dist[start] = Some(G::Weight::zero());
 
     let mut heap = BinaryHeap::new();
-    heap.push((Reverse(Some(W::zero())), start));
+    heap.push((Reverse(G::Weight::zero()), start));
 
     let mut prev = vec![None; g.len()];
 
-    while let Some((Reverse(Some(d)), cur)) = heap.pop() {
+    while let Some((Reverse(d), cur)) = heap.pop() {
         if Some(d) != dist[cur] {
             continue;
         }
-        for &(next, weight) in g[cur]
-            .iter()
-            .filter(|&&(x, w)| dist[x].is_none() || dist[x].unwrap() > d + w)
-            .collect_vec()
-        {
-            dist[next] = Some(d + weight);
-            heap.push((Reverse(Some(d + weight)), next));
-            prev[next] = Some(cur);
+        for (next, weight) in g.edges(cur) {
+            if dist[next].is_none_or(|x| x > d + weight) {
+                dist[next] = Some(d + weight);
+                heap.push((Reverse(d + weight), next));
+                prev[next] = Some(cur);
+            }
         }
     }
 
@@ -0,0 +1,35 @@
+use crate::Graph;
+use itertools::iproduct;
+use std::ops::Add;
+
+/// Floyd-Warshall algorythm
+///
+/// Calculate distances for every pair of nodes on graph.
+/// The diagonal is the length of the shortest cycle through each node, or `None` if there is none.
+///
+/// See https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+pub fn floyd_warshall<G>(g: &G) -> Vec<Vec<Option<G::Weight>>>
+where
+    G: Graph,
+    G::Weight: Ord + Add<Output = G::Weight>,
+{
+    let n = g.len();
+    let mut dist = vec![vec![None; n]; n];
+    for (i, row) in dist.iter_mut().enumerate() {
+        for (j, w) in g.edges(i) {
+            if row[j].is_none_or(|x| x > w) {
+                row[j] = Some(w);
+            }
+        }
+    }
+
+    for (k, i, j) in iproduct!(0..n, 0..n, 0..n) {
+        if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+            if dist[i][j].is_none_or(|x| x > a + b) {
+                dist[i][j] = Some(a + b);
+            }
+        }
+    }
+
+    dist
+}
@@ -1,22 +1,33 @@
-use crate::union_find::UnionFind;
+use crate::{union_find::UnionFind, Graph};
 use itertools::Itertools;
 
 /// Kruskal's algorythm
 ///
-/// Returns the edges of minimum spanning tree of a given weighted graph
+/// Returns the edges of minimum spanning forest of a given weighted undirected graph
 ///
 /// See https://en.wikipedia.org/wiki/Kruskal%27s_algorithm
-pub fn kuruskal<W: Ord + Copy>(n: usize, edges: &[(usize, usize, W)]) -> Vec<(usize, usize, W)> {
+pub fn kuruskal<G>(g: &G) -> Vec<(usize, usize, G::Weight)>
+where
+    G: Graph,
+    G::Weight: Ord,
+{
+    debug_assert!(!g.is_directed());
+
+    let n = g.len();
     let mut uf = UnionFind::new(n);
     let mut mst_edges = Vec::new();
 
-    for &(a, b, c) in edges.iter().sorted_by_key(|x| x.2) {
+    let edges = (0..n)
+        .flat_map(|a| g.edges(a).map(move |(b, c)| (a, b, c)))
+        .filter(|&(a, b, _)| a <= b)
+        .sorted_by_key(|x| x.2);
+    for (a, b, c) in edges {
         if !uf.is_joint(a, b) {
             uf.unite(a, b);
             mst_edges.push((a, b, c));
         }
 
-        if mst_edges.len() == n - 1 {
+        if mst_edges.len() + 1 >= n {
             break;
         }
     }
@@ -26,15 +37,20 @@ pub fn kuruskal<W: Ord + Copy>(n: usize, edges: &[(usize, usize, W)]) -> Vec<(us
 
 #[test]
 fn kruskal_test() {
-    let edges = vec![
-        (0, 1, 2),
-        (1, 2, 3),
-        (0, 2, 6),
-        (1, 3, 5),
-        (3, 4, 9),
-        (2, 4, 8),
-    ];
-
-    let mst = kuruskal(5, &edges);
-    dbg!(mst);
+    use crate::UWLGraph;
+
+    let g = UWLGraph::from_edges(
+        5,
+        &[
+            (0, 1, 2),
+            (1, 2, 3),
+            (0, 2, 6),
+            (1, 3, 5),
+            (3, 4, 9),
+            (2, 4, 8),
+        ],
+    );
+
+    let mst = kuruskal(&g);
+    assert_eq!(mst, vec![(0, 1, 2), (1, 2, 3), (1, 3, 5), (2, 4, 8)]);
 }
@@ -3,7 +3,7 @@ use std::ops::{Add, Sub};
 use itertools::Itertools;
 use num_traits::Zero;
 
-use crate::UWLGraph;
+use crate::{Graph, UWLGraph};
 
 pub struct LowestCommonAncestor<W> {
     n: usize,
@@ -15,7 +15,12 @@ pub struct LowestCommonAncestor<W> {
 
 impl<W: Copy + Add<Output = W> + Sub<Output = W> + Zero> LowestCommonAncestor<W> {
     pub fn new(n: usize, root: usize, edges: &[(usize, usize, W)]) -> Self {
-        let tree = UWLGraph::from_edges(n, edges);
+        Self::from_graph(&UWLGraph::from_edges(n, edges), root)
+    }
+
+    /// Builds from an undirected tree
+    pub fn from_graph<G: Graph<Weight = W>>(tree: &G, root: usize) -> Self {
+        let n = tree.len();
         let mut parent = vec![root; n];
         let mut depth = vec![0usize; n];
         let mut dist = vec![W::zero(); n];
@@ -24,7 +29,7 @@ impl<W: Copy + Add<Output = W> + Sub<Output = W> + Zero> LowestCommonAncestor<W>
         while let Some((cur, prev)) = stack.pop() {
             visited[cur] = true;
             parent[cur] = prev;
-            for &(next, w) in tree.raw_graph[cur].iter().filter(|&&(x, _)| !visited[x]) {
+            for (next, w) in tree.edges(cur).filter(|&(x, _)| !visited[x]) {
                 depth[next] = depth[cur] + 1;
                 dist[next] = dist[cur] + w;
                 stack.push((next, cur));
@@ -1,4 +1,4 @@
-use crate::UWLGraph;
+use crate::{Graph, UWLGraph};
 use itertools::{izip, Itertools};
 use num_traits::Zero;
 use segment_tree::{segment_tree::SegmentTree, Min};
@@ -15,10 +15,12 @@ pub struct LowestCommonAncestor<W> {
 
 impl<W: Copy + Add<Output = W> + Sub<Output = W> + Zero> LowestCommonAncestor<W> {
     pub fn new(n: usize, root: usize, edges: &[(usize, usize, W)]) -> Self {
-        let mut tree = UWLGraph::<W>::new(n);
-        for &(u, v, c) in edges {
-            tree.add_edge(u, v, c);
-        }
+        Self::from_graph(&UWLGraph::from_edges(n, edges), root)
+    }
+
+    /// Builds from an undirected tree
+    pub fn from_graph<G: Graph<Weight = W>>(tree: &G, root: usize) -> Self {
+        let n = tree.len();
         let mut dist = vec![W::zero(); n];
 
         let mut depth = vec![];
@@ -26,7 +28,7 @@ impl<W: Copy + Add<Output = W> + Sub<Output = W> + Zero> LowestCommonAncestor<W>
         let mut first_appear = vec![None; n];
 
         dfs(
-            &tree,
+            tree,
             DfsRecord {
                 eular_tour: &mut eular_tour,
                 depth: &mut depth,
@@ -81,8 +83,8 @@ struct DfsRecord<'a, W> {
     dist: &'a mut [W],
 }
 
-fn dfs<W: Copy + Add<Output = W> + Zero>(
-    graph: &UWLGraph<W>,
+fn dfs<W: Copy + Add<Output = W> + Zero, G: Graph<Weight = W>>(
+    graph: &G,
     DfsRecord {
         eular_tour,
         depth,
@@ -100,10 +102,7 @@ fn dfs<W: Copy + Add<Output = W> + Zero>(
     if let Some(p) = prev {
         dist[cur] = dist[p] + w;
     }
-    for &(next, next_w) in graph.raw_graph[cur]
-        .iter()
-        .filter(|&&(x, _)| Some(x) != prev)
-    {
+    for (next, next_w) in graph.edges(cur).filter(|&(x, _)| Some(x) != prev) {
         dfs(
             graph,
             DfsRecord {
@@ -0,0 +1,207 @@
+use crate::Graph;
+
+/// Lowlink
+///
+/// Computes the preorder `order[v]` of a depth-first search on an undirected graph,
+/// and the lowlink `low[v]`, the minimum order reachable from `v`
+/// by going down the DFS tree and then using at most one back edge.
+/// It finds bridges and articulation points.
+/// Multiple edges are handled correctly, and self-loops are ignored.
+///
+/// See https://en.wikipedia.org/wiki/Bridge_(graph_theory)#Tarjan's_bridge-finding_algorithm
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::lowlink::Lowlink, UULGraph};
+///
+/// //   1     4
+/// //  / \   /
+/// // 0 - 2 - 3 = 5
+/// let g = UULGraph::from_edges(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (3, 5), (3, 5)]);
+/// let lowlink = Lowlink::new(&g);
+/// assert_eq!(lowlink.bridges(), vec![(2, 3), (3, 4)]);
+/// assert_eq!(lowlink.articulation_points(), vec![2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lowlink {
+    order: Vec<usize>,
+    low: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    preorder: Vec<usize>,
+    articulation: Vec<bool>,
+}
+
+impl Lowlink {
+    pub fn new<G: Graph>(g: &G) -> Self {
+        debug_assert!(!g.is_directed());
+
+        let n = g.len();
+        let mut order = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut parent = vec![None; n];
+        let mut preorder = Vec::with_capacity(n);
+
+        for s in 0..n {
+            if order[s] != usize::MAX {
+                continue;
+            }
+
+            order[s] = preorder.len();
+            low[s] = preorder.len();
+            preorder.push(s);
+            // The flag tells whether the edge to the parent has been skipped,
+            // so that the other edges parallel to it are regarded as back edges
+            let mut call_stack = vec![(s, g.neighbors(s), false)];
+
+            while let Some((v, neighbors, skipped)) = call_stack.last_mut() {
+                let v = *v;
+                if let Some(w) = neighbors.next() {
+                    if order[w] == usize::MAX {
+                        parent[w] = Some(v);
+                        order[w] = preorder.len();
+                        low[w] = preorder.len();
+                        preorder.push(w);
+                        call_stack.push((w, g.neighbors(w), false));
+                    } else if !*skipped && parent[v] == Some(w) {
+                        *skipped = true;
+                    } else {
+                        low[v] = low[v].min(order[w]);
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some(&(p, ..)) = call_stack.last() {
+                        low[p] = low[p].min(low[v]);
+                    }
+                }
+            }
+        }
+
+        // A root is an articulation point iff it has two or more children,
+        // and the others iff a child cannot reach above them
+        let mut articulation = vec![false; n];
+        let mut children = vec![0; n];
+        for v in 0..n {
+            if let Some(p) = parent[v] {
+                children[p] += 1;
+                if parent[p].is_some() && low[v] >= order[p] {
+                    articulation[p] = true;
+                }
+            }
+        }
+        for v in 0..n {
+            if parent[v].is_none() && children[v] >= 2 {
+                articulation[v] = true;
+            }
+        }
+
+        Self {
+            order,
+            low,
+            parent,
+            preorder,
+            articulation,
+        }
+    }
+
+    /// Returns the preorder of `v`
+    pub fn order(&self, v: usize) -> usize {
+        self.order[v]
+    }
+
+    /// Returns the lowlink of `v`
+    pub fn low(&self, v: usize) -> usize {
+        self.low[v]
+    }
+
+    /// Returns the parent of `v` in the DFS tree, or `None` if `v` is a root
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+
+    /// Returns the nodes in preorder
+    pub fn preorder(&self) -> &[usize] {
+        &self.preorder
+    }
+
+    /// Returns whether the edge `u - v` is a bridge, where `u - v` must be an edge
+    pub fn is_bridge(&self, u: usize, v: usize) -> bool {
+        let (p, c) = if self.parent[v] == Some(u) {
+            (u, v)
+        } else if self.parent[u] == Some(v) {
+            (v, u)
+        } else {
+            return false;
+        };
+        self.low[c] > self.order[p]
+    }
+
+    /// Returns the bridges as `(parent, child)` in the DFS tree, sorted
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        let mut bridges = (0..self.parent.len())
+            .filter_map(|v| self.parent[v].map(|p| (p, v)))
+            .filter(|&(p, v)| self.low[v] > self.order[p])
+            .collect::<Vec<_>>();
+        bridges.sort();
+        bridges
+    }
+
+    pub fn is_articulation_point(&self, v: usize) -> bool {
+        self.articulation[v]
+    }
+
+    /// Returns the articulation points in ascending order
+    pub fn articulation_points(&self) -> Vec<usize> {
+        (0..self.articulation.len())
+            .filter(|&v| self.articulation[v])
+            .collect()
+    }
+}
+
+#[test]
+fn lowlink_test() {
+    use crate::{union_find::UnionFind, UULGraph, UWLGraph};
+    use rand::Rng;
+
+    let components = |n: usize, edges: &[(usize, usize)], removed: Option<usize>| {
+        let mut uf = UnionFind::new(n);
+        for &(u, v) in edges.iter() {
+            if removed != Some(u) && removed != Some(v) {
+                uf.unite(u, v);
+            }
+        }
+        uf.trees_count() - removed.is_some() as usize
+    };
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..300 {
+        let n = rng.gen_range(1..12);
+        let m = rng.gen_range(0..20);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+        let g = UULGraph::from_edges(n, &edges);
+        let lowlink = Lowlink::new(&g);
+        let weighted = UWLGraph::from_edges(
+            n,
+            &edges.iter().map(|&(u, v)| (u, v, 1)).collect::<Vec<_>>(),
+        );
+        assert_eq!(lowlink.bridges(), Lowlink::new(&weighted).bridges());
+
+        let count = components(n, &edges, None);
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            let mut rest = edges.clone();
+            rest.remove(i);
+            let bridge = components(n, &rest, None) > count;
+            assert_eq!(lowlink.is_bridge(u, v), bridge);
+            assert_eq!(
+                lowlink.bridges().contains(&(u, v)),
+                bridge && lowlink.parent(v) == Some(u)
+            );
+        }
+        for v in 0..n {
+            let articulation = components(n, &edges, Some(v)) > count;
+            assert_eq!(lowlink.is_articulation_point(v), articulation);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{DULGraph, Graph};
+use itertools::Itertools;
+
+/// Tarjan's strongly connected components algorythm
+///
+/// Decomposes a directed graph into strongly connected components,
+/// numbered in topological order:
+/// for every edge `u -> v`, `id(u) ≤ id(v)` holds.
+///
+/// See https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::strongly_connected_components::StronglyConnectedComponents, DULGraph};
+///
+/// let g = DULGraph::from_edges(5, &[(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (4, 3)]);
+/// let scc = StronglyConnectedComponents::new(&g);
+/// assert_eq!(scc.count(), 3);
+/// assert_eq!(scc.groups(), vec![vec![4], vec![0, 1], vec![2, 3]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StronglyConnectedComponents {
+    count: usize,
+    ids: Vec<usize>,
+}
+
+impl StronglyConnectedComponents {
+    pub fn new<G: Graph>(g: &G) -> Self {
+        debug_assert!(g.is_directed());
+
+        let n = g.len();
+        let mut order = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut ids = vec![usize::MAX; n];
+        let mut stack = vec![];
+        let mut counter = 0;
+        let mut count = 0;
+
+        for s in 0..n {
+            if order[s] != usize::MAX {
+                continue;
+            }
+
+            order[s] = counter;
+            low[s] = counter;
+            counter += 1;
+            stack.push(s);
+            let mut call_stack = vec![(s, g.neighbors(s))];
+
+            while let Some((v, neighbors)) = call_stack.last_mut() {
+                let v = *v;
+                if let Some(w) = neighbors.next() {
+                    if order[w] == usize::MAX {
+                        order[w] = counter;
+                        low[w] = counter;
+                        counter += 1;
+                        stack.push(w);
+                        call_stack.push((w, g.neighbors(w)));
+                    } else if ids[w] == usize::MAX {
+                        // `w` is still on the stack
+                        low[v] = low[v].min(order[w]);
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some(&(p, _)) = call_stack.last() {
+                        low[p] = low[p].min(low[v]);
+                    }
+                    if low[v] == order[v] {
+                        while let Some(w) = stack.pop() {
+                            ids[w] = count;
+                            if w == v {
+                                break;
+                            }
+                        }
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        // Tarjan's algorythm finds the components in reverse topological order
+        for id in ids.iter_mut() {
+            *id = count - 1 - *id;
+        }
+
+        Self { count, ids }
+    }
+
+    /// Returns the number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component id of `v`
+    pub fn id(&self, v: usize) -> usize {
+        self.ids[v]
+    }
+
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    /// Returns the nodes of each component in topological order
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = vec![vec![]; self.count];
+        for (v, &id) in self.ids.iter().enumerate() {
+            groups[id].push(v);
+        }
+        groups
+    }
+
+    /// Returns the condensation of `g`, a DAG whose nodes are the components,
+    /// without multiple edges
+    pub fn condensation<G: Graph>(&self, g: &G) -> DULGraph {
+        let edges = (0..g.len())
+            .flat_map(|u| g.neighbors(u).map(move |v| (self.ids[u], self.ids[v])))
+            .filter(|&(a, b)| a != b)
+            .sorted()
+            .dedup()
+            .collect_vec();
+        DULGraph::from_edges(self.count, &edges)
+    }
+}
+
+#[test]
+fn scc_test() {
+    use crate::{algorythm::bfs::bfs, DWLGraph};
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..200 {
+        let n = rng.gen_range(1..30);
+        let m = rng.gen_range(0..60);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect_vec();
+        let g = DULGraph::from_edges(n, &edges);
+        let weighted =
+            DWLGraph::from_edges(n, &edges.iter().map(|&(u, v)| (u, v, 1)).collect_vec());
+
+        let scc = StronglyConnectedComponents::new(&g);
+        assert_eq!(scc.ids(), StronglyConnectedComponents::new(&weighted).ids());
+
+        let reachable = (0..n).map(|s| bfs(&g, s)).collect_vec();
+        for (u, v) in itertools::iproduct!(0..n, 0..n) {
+            let strongly_connected = reachable[u][v].is_some() && reachable[v][u].is_some();
+            assert_eq!(scc.id(u) == scc.id(v), strongly_connected);
+        }
+        for &(u, v) in edges.iter() {
+            assert!(scc.id(u) <= scc.id(v));
+        }
+
+        let dag = scc.condensation(&g);
+        assert_eq!(dag.len(), scc.count());
+        for a in 0..dag.len() {
+            let next = dag.neighbors(a).collect_vec();
+            assert!(next.iter().all(|&b| a < b));
+            assert!(next.iter().tuple_windows().all(|(x, y)| x < y));
+        }
+        for &(u, v) in edges.iter() {
+            let (a, b) = (scc.id(u), scc.id(v));
+            assert!(a == b || dag.neighbors(a).contains(&b));
+        }
+    }
+}
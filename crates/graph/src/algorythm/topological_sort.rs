@@ -0,0 +1,271 @@
+use crate::Graph;
+use modint::StaticModInt;
+use num_traits::{One, Zero};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+fn in_degrees<G: Graph>(g: &G) -> Vec<usize> {
+    let mut in_degree = vec![0; g.len()];
+    for v in (0..g.len()).flat_map(|u| g.neighbors(u)) {
+        in_degree[v] += 1;
+    }
+    in_degree
+}
+
+/// Kahn's algorythm
+///
+/// Returns the nodes of a directed graph in topological order,
+/// or `None` if the graph has a cycle.
+///
+/// See https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::topological_sort::topological_sort, DULGraph};
+///
+/// let g = DULGraph::from_edges(4, &[(0, 1), (2, 1), (1, 3)]);
+/// assert_eq!(topological_sort(&g), Some(vec![0, 2, 1, 3]));
+///
+/// let g = DULGraph::from_edges(2, &[(0, 1), (1, 0)]);
+/// assert_eq!(topological_sort(&g), None);
+/// ```
+pub fn topological_sort<G: Graph>(g: &G) -> Option<Vec<usize>> {
+    debug_assert!(g.is_directed());
+
+    let mut in_degree = in_degrees(g);
+    let mut q = (0..g.len())
+        .filter(|&v| in_degree[v] == 0)
+        .collect::<VecDeque<_>>();
+
+    let mut order = Vec::with_capacity(g.len());
+    while let Some(cur) = q.pop_front() {
+        order.push(cur);
+        for next in g.neighbors(cur) {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                q.push_back(next);
+            }
+        }
+    }
+
+    (order.len() == g.len()).then_some(order)
+}
+
+/// Returns the lexicographically smallest topological order,
+/// or `None` if the graph has a cycle.
+///
+/// Complexity: `O(V log V + E)`
+///
+/// ```
+/// use graph::{algorythm::topological_sort::lexicographically_smallest_topological_sort, DULGraph};
+///
+/// let g = DULGraph::from_edges(4, &[(3, 0), (2, 1)]);
+/// assert_eq!(lexicographically_smallest_topological_sort(&g), Some(vec![2, 1, 3, 0]));
+/// ```
+pub fn lexicographically_smallest_topological_sort<G: Graph>(g: &G) -> Option<Vec<usize>> {
+    debug_assert!(g.is_directed());
+
+    let mut in_degree = in_degrees(g);
+    let mut heap = (0..g.len())
+        .filter(|&v| in_degree[v] == 0)
+        .map(Reverse)
+        .collect::<BinaryHeap<_>>();
+
+    let mut order = Vec::with_capacity(g.len());
+    while let Some(Reverse(cur)) = heap.pop() {
+        order.push(cur);
+        for next in g.neighbors(cur) {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                heap.push(Reverse(next));
+            }
+        }
+    }
+
+    (order.len() == g.len()).then_some(order)
+}
+
+/// Returns the nodes `v_0, v_1, ..., v_k` of a directed cycle,
+/// where `v_i -> v_(i+1)` and `v_k -> v_0` are edges,
+/// or `None` if the graph is acyclic.
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::topological_sort::find_cycle, DULGraph};
+///
+/// let g = DULGraph::from_edges(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+/// assert_eq!(find_cycle(&g), Some(vec![1, 2, 3]));
+/// ```
+pub fn find_cycle<G: Graph>(g: &G) -> Option<Vec<usize>> {
+    debug_assert!(g.is_directed());
+
+    const UNVISITED: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+
+    let mut state = vec![UNVISITED; g.len()];
+    for s in 0..g.len() {
+        if state[s] != UNVISITED {
+            continue;
+        }
+
+        state[s] = ON_STACK;
+        let mut path = vec![s];
+        let mut call_stack = vec![g.neighbors(s)];
+        while let Some(neighbors) = call_stack.last_mut() {
+            match neighbors.next() {
+                Some(next) if state[next] == UNVISITED => {
+                    state[next] = ON_STACK;
+                    path.push(next);
+                    call_stack.push(g.neighbors(next));
+                }
+                Some(next) if state[next] == ON_STACK => {
+                    let i = path.iter().rposition(|&v| v == next).unwrap();
+                    return Some(path.split_off(i));
+                }
+                Some(_) => {}
+                None => {
+                    state[path.pop().unwrap()] = DONE;
+                    call_stack.pop();
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the maximum weight of the paths ending at each node of a DAG,
+/// which is zero for a path with no edges,
+/// or `None` if the graph has a cycle.
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::topological_sort::longest_path, DWLGraph};
+///
+/// let g = DWLGraph::from_edges(4, &[(0, 1, 2), (0, 2, 5), (1, 2, 4), (2, 3, 1)]);
+/// assert_eq!(longest_path(&g), Some(vec![0, 2, 6, 7]));
+/// ```
+pub fn longest_path<G>(g: &G) -> Option<Vec<G::Weight>>
+where
+    G: Graph,
+    G::Weight: Ord + Zero,
+{
+    let order = topological_sort(g)?;
+    let mut dist = vec![G::Weight::zero(); g.len()];
+    for cur in order {
+        for (next, w) in g.edges(cur) {
+            dist[next] = dist[next].max(dist[cur] + w);
+        }
+    }
+    Some(dist)
+}
+
+/// Returns the number of paths from `start` to each node of a DAG modulo `M`,
+/// or `None` if the graph has a cycle.
+/// Multiple edges are counted as different paths.
+///
+/// Complexity: `O(V + E)`
+///
+/// ```
+/// use graph::{algorythm::topological_sort::count_paths, DULGraph};
+///
+/// let g = DULGraph::from_edges(4, &[(0, 1), (0, 2), (1, 3), (2, 3), (1, 2)]);
+/// let count = count_paths::<_, 998244353>(&g, 0).unwrap();
+/// assert_eq!(count.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![1, 1, 2, 3]);
+/// ```
+pub fn count_paths<G: Graph, const M: u64>(g: &G, start: usize) -> Option<Vec<StaticModInt<M>>> {
+    let order = topological_sort(g)?;
+    let mut count = vec![StaticModInt::zero(); g.len()];
+    count[start] = StaticModInt::one();
+    for cur in order {
+        let c = count[cur];
+        for next in g.neighbors(cur) {
+            count[next] += c;
+        }
+    }
+    Some(count)
+}
+
+#[test]
+fn topological_sort_test() {
+    use crate::{DULGraph, DWLGraph};
+    use itertools::Itertools;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..300 {
+        let n = rng.gen_range(1..9);
+        let m = rng.gen_range(0..12);
+        let acyclic = rng.gen_bool(0.5);
+        let edges = (0..m)
+            .map(|_| {
+                let u = rng.gen_range(0..n);
+                let v = rng.gen_range(0..n);
+                if acyclic {
+                    (u.min(v), u.max(v))
+                } else {
+                    (u, v)
+                }
+            })
+            .filter(|&(u, v)| !acyclic || u != v)
+            .collect_vec();
+        let g = DULGraph::from_edges(n, &edges);
+
+        let is_order = |order: &[usize]| {
+            let mut pos = vec![0; n];
+            for (i, &v) in order.iter().enumerate() {
+                pos[v] = i;
+            }
+            edges.iter().all(|&(u, v)| pos[u] < pos[v])
+        };
+        let smallest = (0..n).permutations(n).find(|p| is_order(p));
+
+        match topological_sort(&g) {
+            Some(order) => {
+                assert!(order.iter().copied().sorted().eq(0..n));
+                assert!(is_order(&order));
+            }
+            None => assert!(smallest.is_none()),
+        }
+        assert_eq!(lexicographically_smallest_topological_sort(&g), smallest);
+
+        match find_cycle(&g) {
+            Some(cycle) => {
+                assert!(!cycle.is_empty());
+                for (&u, &v) in cycle.iter().circular_tuple_windows() {
+                    assert!(edges.contains(&(u, v)));
+                }
+                assert!(cycle.iter().all_unique());
+            }
+            None => assert!(smallest.is_some()),
+        }
+
+        if let Some(order) = smallest {
+            let weights = edges
+                .iter()
+                .map(|&(u, v)| (u, v, rng.gen_range(0..10u64)))
+                .collect_vec();
+            let longest = longest_path(&DWLGraph::from_edges(n, &weights)).unwrap();
+            let count = count_paths::<_, 998244353>(&g, 0).unwrap();
+
+            // dynamic programming in the order by brute force
+            let mut expected_longest = vec![0; n];
+            let mut expected_count = vec![0u64; n];
+            expected_count[0] = 1;
+            for &u in order.iter() {
+                for &(a, b, w) in weights.iter().filter(|e| e.0 == u) {
+                    expected_longest[b] = expected_longest[b].max(expected_longest[a] + w);
+                    expected_count[b] += expected_count[a];
+                }
+            }
+            assert_eq!(longest, expected_longest);
+            assert!(count.iter().map(|x| x.get()).eq(expected_count));
+        }
+    }
+}
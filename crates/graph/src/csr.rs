@@ -0,0 +1,274 @@
+use crate::{
+    algorythm::{
+        bfs::bfs,
+        dijkstra::{dijkstra, dijkstra_with_path_hint},
+        lowest_common_ancestor::LowestCommonAncestor,
+    },
+    Graph,
+};
+use num_traits::Zero;
+use std::ops::{Add, Sub};
+
+/// Static graph in compressed sparse row format
+///
+/// The adjacencies of all the nodes are stored in one contiguous array,
+/// so that it is cache-friendly and needs only a few allocations.
+/// Each edge keeps the index in the given edge list as its id.
+///
+/// See https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
+///
+/// ```
+/// use graph::{csr::DCsrGraph, Graph};
+///
+/// let g = DCsrGraph::from_edges(4, &[(0, 1, 5), (0, 2, 1), (2, 1, 1), (1, 3, 1)]);
+/// assert_eq!(g.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(g.in_edges(1).collect::<Vec<_>>(), vec![(0, 5, 0), (2, 1, 2)]);
+/// assert_eq!(g.dijkstra(0), vec![Some(0), Some(2), Some(1), Some(3)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrGraph<const D: bool, W> {
+    len: usize,
+    edges: Vec<(usize, usize, W)>,
+    /// Adjacencies of `v` are `adjacency[start[v]..start[v + 1]]`
+    start: Vec<usize>,
+    adjacency: Vec<(usize, W)>,
+    ids: Vec<usize>,
+    /// Incoming edges in the same layout, only for directed graphs
+    in_start: Vec<usize>,
+    in_adjacency: Vec<(usize, W)>,
+    in_ids: Vec<usize>,
+}
+
+pub type DCsrGraph<W> = CsrGraph<true, W>;
+pub type UCsrGraph<W> = CsrGraph<false, W>;
+
+/// Returns `(start, adjacency, ids)` of the arcs `(from, to, weight, id)`
+fn build_csr<W: Copy>(
+    len: usize,
+    arcs: impl Iterator<Item = (usize, usize, W, usize)>,
+) -> (Vec<usize>, Vec<(usize, W)>, Vec<usize>) {
+    let arcs = arcs.collect::<Vec<_>>();
+    let mut start = vec![0; len + 1];
+    for &(from, ..) in arcs.iter() {
+        start[from + 1] += 1;
+    }
+    for i in 0..len {
+        start[i + 1] += start[i];
+    }
+
+    // Counting sort by `from`, keeping the input order
+    let mut pos = start.clone();
+    let mut order = vec![0; arcs.len()];
+    for (i, &(from, ..)) in arcs.iter().enumerate() {
+        order[pos[from]] = i;
+        pos[from] += 1;
+    }
+
+    let adjacency = order.iter().map(|&i| (arcs[i].1, arcs[i].2)).collect();
+    let ids = order.iter().map(|&i| arcs[i].3).collect();
+    (start, adjacency, ids)
+}
+
+impl<const D: bool, W: Copy> CsrGraph<D, W> {
+    pub fn from_edges(len: usize, edges: &[(usize, usize, W)]) -> Self {
+        let forward = edges
+            .iter()
+            .enumerate()
+            .map(|(id, &(from, to, w))| (from, to, w, id));
+        let backward = edges
+            .iter()
+            .enumerate()
+            .map(|(id, &(from, to, w))| (to, from, w, id));
+
+        let (start, adjacency, ids, in_start, in_adjacency, in_ids) = if D {
+            let (start, adjacency, ids) = build_csr(len, forward);
+            let (in_start, in_adjacency, in_ids) = build_csr(len, backward);
+            (start, adjacency, ids, in_start, in_adjacency, in_ids)
+        } else {
+            // Each undirected edge is stored in both directions, as WeightedListGraph does
+            let (start, adjacency, ids) = build_csr(len, forward.chain(backward));
+            (start, adjacency, ids, vec![], vec![], vec![])
+        };
+
+        Self {
+            len,
+            edges: edges.to_vec(),
+            start,
+            adjacency,
+            ids,
+            in_start,
+            in_adjacency,
+            in_ids,
+        }
+    }
+
+    pub fn from_edges1(len: usize, edges: &[(usize, usize, W)]) -> Self {
+        let edges = edges
+            .iter()
+            .map(|&(from, to, w)| (from - 1, to - 1, w))
+            .collect::<Vec<_>>();
+        Self::from_edges(len, &edges)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of edges given on construction
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the edge `(from, to, weight)` with the id
+    pub fn edge(&self, id: usize) -> (usize, usize, W) {
+        self.edges[id]
+    }
+
+    /// Returns an iterator over `(to, weight, id)` of the edges going out of `from`
+    pub fn edges_with_ids(&self, from: usize) -> impl Iterator<Item = (usize, W, usize)> + '_ {
+        let range = self.start[from]..self.start[from + 1];
+        self.adjacency[range.clone()]
+            .iter()
+            .zip(&self.ids[range])
+            .map(|(&(to, w), &id)| (to, w, id))
+    }
+
+    /// Returns an iterator over `(from, weight, id)` of the edges coming into `to`.
+    /// For undirected graphs, it is the same as [`edges_with_ids`](Self::edges_with_ids).
+    pub fn in_edges(&self, to: usize) -> impl Iterator<Item = (usize, W, usize)> + '_ {
+        let (start, adjacency, ids) = if D {
+            (&self.in_start, &self.in_adjacency, &self.in_ids)
+        } else {
+            (&self.start, &self.adjacency, &self.ids)
+        };
+        let range = start[to]..start[to + 1];
+        adjacency[range.clone()]
+            .iter()
+            .zip(&ids[range])
+            .map(|(&(from, w), &id)| (from, w, id))
+    }
+
+    pub fn distances(&self, start: usize) -> Vec<Option<usize>> {
+        bfs(self, start)
+    }
+}
+
+impl<const D: bool, W: Copy + Ord + Zero> CsrGraph<D, W> {
+    /// Dijkstra algorythm
+    ///
+    /// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+    pub fn dijkstra(&self, start: usize) -> Vec<Option<W>> {
+        dijkstra(self, start)
+    }
+
+    /// Dijkstra algorythm with path
+    ///
+    /// Returns a pair of (dist, prev),
+    /// to restore the shortest path from `start` to `x`,
+    /// call `x = prev[x]` repeatedly
+    ///
+    /// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+    pub fn dijkstra_with_path_hints(&self, start: usize) -> (Vec<Option<W>>, Vec<Option<usize>>) {
+        dijkstra_with_path_hint(self, start)
+    }
+}
+
+impl<W: Copy + Add<Output = W> + Sub<Output = W> + Zero> UCsrGraph<W> {
+    /// Builds [`LowestCommonAncestor`] regarding the graph as a tree rooted at `root`
+    pub fn lowest_common_ancestor(&self, root: usize) -> LowestCommonAncestor<W> {
+        LowestCommonAncestor::from_graph(self, root)
+    }
+}
+
+impl<const D: bool, W: Copy> Graph for CsrGraph<D, W> {
+    type Weight = W;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_directed(&self) -> bool {
+        D
+    }
+
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.adjacency[self.start[from]..self.start[from + 1]]
+            .iter()
+            .copied()
+    }
+}
+
+#[test]
+fn csr_test() {
+    use crate::{DWLGraph, UWLGraph};
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let n = rng.gen_range(1..50);
+        let m = rng.gen_range(0..200);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..100u64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let dcsr = DCsrGraph::from_edges(n, &edges);
+        let dwl = DWLGraph::from_edges(n, &edges);
+        let ucsr = UCsrGraph::from_edges(n, &edges);
+        let uwl = UWLGraph::from_edges(n, &edges);
+        assert_eq!(dcsr.edge_count(), m);
+
+        for v in 0..n {
+            let mut a = dcsr.edges(v).collect::<Vec<_>>();
+            let mut b = dwl.edges(v).collect::<Vec<_>>();
+            a.sort();
+            b.sort();
+            assert_eq!(a, b);
+
+            let mut a = ucsr.edges(v).collect::<Vec<_>>();
+            let mut b = uwl.edges(v).collect::<Vec<_>>();
+            a.sort();
+            b.sort();
+            assert_eq!(a, b);
+
+            for (to, w, id) in dcsr.edges_with_ids(v) {
+                assert_eq!(dcsr.edge(id), (v, to, w));
+                assert!(dcsr.in_edges(to).any(|e| e == (v, w, id)));
+            }
+            for (from, w, id) in dcsr.in_edges(v) {
+                assert_eq!(dcsr.edge(id), (from, v, w));
+            }
+            for (to, w, id) in ucsr.edges_with_ids(v) {
+                let (a, b, c) = ucsr.edge(id);
+                assert!((a, b, c) == (v, to, w) || (a, b, c) == (to, v, w));
+            }
+        }
+
+        let start = rng.gen_range(0..n);
+        assert_eq!(dcsr.distances(start), bfs(&dwl, start));
+        assert_eq!(dcsr.dijkstra(start), dwl.dijkstra(start));
+        assert_eq!(ucsr.dijkstra(start), uwl.dijkstra(start));
+
+        // random tree
+        let tree = (1..n)
+            .map(|v| (rng.gen_range(0..v), v, rng.gen_range(0..100u64)))
+            .collect::<Vec<_>>();
+        let lca = UCsrGraph::from_edges(n, &tree).lowest_common_ancestor(0);
+        let expected = LowestCommonAncestor::new(n, 0, &tree);
+        for _ in 0..10 {
+            let a = rng.gen_range(0..n);
+            let b = rng.gen_range(0..n);
+            assert_eq!(lca.lca(a, b), expected.lca(a, b));
+            assert_eq!(lca.distance(a, b), expected.distance(a, b));
+        }
+    }
+}
@@ -1,10 +1,51 @@
-use itertools::{iproduct, Itertools};
+use algorythm::{
+    biconnected_components::{BiconnectedComponents, TwoEdgeConnectedComponents},
+    lowlink::Lowlink,
+    strongly_connected_components::StronglyConnectedComponents,
+};
+use itertools::Itertools;
 use num_traits::Unsigned;
-use std::{collections::VecDeque, ops::Add};
+use std::ops::Add;
 
 pub mod algorythm;
+pub mod csr;
+pub mod two_sat;
 pub mod union_find;
 
+/// Common interface of the graph representations
+///
+/// Unweighted graphs have `usize` weights, every edge weighing `1`,
+/// so that weighted algorythms run on them as well.
+///
+/// ```
+/// use graph::{algorythm::dijkstra::dijkstra, Graph, DUMGraph};
+///
+/// let g = DUMGraph::from_edges(4, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+/// assert_eq!(g.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(dijkstra(&g, 0), vec![Some(0), Some(1), Some(1), Some(2)]);
+/// ```
+pub trait Graph {
+    type Weight: Copy;
+
+    /// Returns the number of nodes
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn is_directed(&self) -> bool;
+
+    /// Returns an iterator over the pairs of the destination and the weight
+    /// of the edges going out of `from`
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, Self::Weight)> + '_;
+
+    /// Returns an iterator over the nodes adjacent to `from`
+    fn neighbors(&self, from: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges(from).map(|(to, _)| to)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnweightedListGraph<const D: bool> {
     len: usize,
@@ -60,24 +101,51 @@ impl<const D: bool> UnweightedListGraph<D> {
     }
 
     pub fn distances(&self, start: usize) -> Vec<Option<usize>> {
-        let mut dist = vec![None; self.len()];
-        dist[start] = Some(0);
-        let mut q = VecDeque::new();
-        q.push_back(start);
-
-        while let Some(cur) = q.pop_front() {
-            for next in self.raw_graph[cur]
-                .iter()
-                .copied()
-                .filter(|&x| dist[x].is_none())
-                .collect_vec()
-            {
-                dist[next] = Some(dist[cur].unwrap() + 1);
-                q.push_back(next);
-            }
-        }
+        crate::algorythm::bfs::bfs(self, start)
+    }
+}
 
-        dist
+impl DULGraph {
+    /// Tarjan's strongly connected components algorythm
+    ///
+    /// See [`StronglyConnectedComponents`]
+    pub fn strongly_connected_components(&self) -> StronglyConnectedComponents {
+        StronglyConnectedComponents::new(self)
+    }
+}
+
+impl UULGraph {
+    /// Lowlink, which finds bridges and articulation points
+    ///
+    /// See [`Lowlink`]
+    pub fn lowlink(&self) -> Lowlink {
+        Lowlink::new(self)
+    }
+
+    /// See [`TwoEdgeConnectedComponents`]
+    pub fn two_edge_connected_components(&self) -> TwoEdgeConnectedComponents {
+        TwoEdgeConnectedComponents::new(self)
+    }
+
+    /// See [`BiconnectedComponents`]
+    pub fn biconnected_components(&self) -> BiconnectedComponents {
+        BiconnectedComponents::new(self)
+    }
+}
+
+impl<const D: bool> Graph for UnweightedListGraph<D> {
+    type Weight = usize;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_directed(&self) -> bool {
+        D
+    }
+
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.raw_graph[from].iter().map(|&to| (to, 1))
     }
 }
 
@@ -143,24 +211,7 @@ impl<const D: bool> UnweightedMatrixGraph<D> {
     }
 
     pub fn distances(&self, start: usize) -> Vec<Option<usize>> {
-        let mut dist = vec![None; self.len()];
-        dist[start] = Some(0);
-        let mut q = VecDeque::new();
-        q.push_back(start);
-
-        while let Some(cur) = q.pop_front() {
-            for next in self.raw_graph[cur]
-                .iter()
-                .positions(|&x| x)
-                .filter(|&x| dist[x].is_none())
-                .collect_vec()
-            {
-                dist[next] = dist[cur].map(|x| x + 1);
-                q.push_back(next);
-            }
-        }
-
-        dist
+        crate::algorythm::bfs::bfs(self, start)
     }
 
     /// Floyd-Warshall algorythm
@@ -169,34 +220,36 @@ impl<const D: bool> UnweightedMatrixGraph<D> {
     ///
     /// See https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
     pub fn floyd_warshall(&self) -> Vec<Vec<Option<usize>>> {
-        let mut dist = (0..self.len())
-            .map(|i| {
-                (0..self.len())
-                    .map(|j| {
-                        if self.raw_graph[i][j] {
-                            Some(1)
-                        } else if i == j {
-                            Some(0)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect_vec()
-            })
-            .collect_vec();
-
-        for (k, i, j) in iproduct!(0..self.len(), 0..self.len(), 0..self.len()) {
-            if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
-                if dist[i][j].is_none() || dist[i][j].unwrap() > a + b {
-                    dist[i][j] = Some(a + b);
-                }
+        let mut dist = crate::algorythm::floyd_warshall::floyd_warshall(self);
+        // A node without a self-loop is at distance zero from itself
+        for (i, row) in dist.iter_mut().enumerate() {
+            if !self.raw_graph[i][i] {
+                row[i] = Some(0);
             }
         }
-
         dist
     }
 }
 
+impl<const D: bool> Graph for UnweightedMatrixGraph<D> {
+    type Weight = usize;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_directed(&self) -> bool {
+        D
+    }
+
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.raw_graph[from]
+            .iter()
+            .positions(|&x| x)
+            .map(|to| (to, 1))
+    }
+}
+
 #[test]
 fn uum_dist_test() {
     let g = UUMGraph::from_edges1(5, &[(1, 2), (2, 3), (2, 4), (1, 5)]);
@@ -266,12 +319,56 @@ impl<const D: bool, W: Copy> WeightedListGraph<D, W> {
     }
 }
 
+impl<W: Copy> DWLGraph<W> {
+    /// Tarjan's strongly connected components algorythm
+    ///
+    /// See [`StronglyConnectedComponents`]
+    pub fn strongly_connected_components(&self) -> StronglyConnectedComponents {
+        StronglyConnectedComponents::new(self)
+    }
+}
+
+impl<W: Copy> UWLGraph<W> {
+    /// Lowlink, which finds bridges and articulation points
+    ///
+    /// See [`Lowlink`]
+    pub fn lowlink(&self) -> Lowlink {
+        Lowlink::new(self)
+    }
+
+    /// See [`TwoEdgeConnectedComponents`]
+    pub fn two_edge_connected_components(&self) -> TwoEdgeConnectedComponents {
+        TwoEdgeConnectedComponents::new(self)
+    }
+
+    /// See [`BiconnectedComponents`]
+    pub fn biconnected_components(&self) -> BiconnectedComponents {
+        BiconnectedComponents::new(self)
+    }
+}
+
+impl<const D: bool, W: Copy> Graph for WeightedListGraph<D, W> {
+    type Weight = W;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_directed(&self) -> bool {
+        D
+    }
+
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.raw_graph[from].iter().copied()
+    }
+}
+
 impl<const D: bool, W: Copy + Ord + Unsigned> WeightedListGraph<D, W> {
     /// Dijkstra algorythm
     ///
     /// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
     pub fn dijkstra(&self, start: usize) -> Vec<Option<W>> {
-        crate::algorythm::dijkstra::dijkstra(&self.raw_graph, start)
+        crate::algorythm::dijkstra::dijkstra(self, start)
     }
 
     /// Dijkstra algorythm with path
@@ -282,7 +379,7 @@ impl<const D: bool, W: Copy + Ord + Unsigned> WeightedListGraph<D, W> {
     ///
     /// See https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
     pub fn dijkstra_with_path_hints(&self, start: usize) -> (Vec<Option<W>>, Vec<Option<usize>>) {
-        crate::algorythm::dijkstra::dijkstra_with_path_hint(&self.raw_graph, start)
+        crate::algorythm::dijkstra::dijkstra_with_path_hint(self, start)
     }
 }
 
@@ -338,7 +435,7 @@ impl<const D: bool, W: Copy> WeightedMatrixGraph<D, W> {
         graph
     }
 
-    pub fn from_edge1s(len: usize, edges: &[(usize, usize, W)]) -> Self {
+    pub fn from_edges1(len: usize, edges: &[(usize, usize, W)]) -> Self {
         let mut graph = Self::new(len);
         for &(from, to, cost) in edges {
             graph.add_edge(from - 1, to - 1, cost);
@@ -346,6 +443,11 @@ impl<const D: bool, W: Copy> WeightedMatrixGraph<D, W> {
         graph
     }
 
+    #[deprecated(note = "use `from_edges1` instead")]
+    pub fn from_edge1s(len: usize, edges: &[(usize, usize, W)]) -> Self {
+        Self::from_edges1(len, edges)
+    }
+
     pub fn adjacencies(&self, from: usize) -> Vec<usize> {
         self.raw_graph[from]
             .iter()
@@ -354,23 +456,76 @@ impl<const D: bool, W: Copy> WeightedMatrixGraph<D, W> {
     }
 }
 
+impl<const D: bool, W: Copy> Graph for WeightedMatrixGraph<D, W> {
+    type Weight = W;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_directed(&self) -> bool {
+        D
+    }
+
+    fn edges(&self, from: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.raw_graph[from]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, &w)| w.map(|w| (to, w)))
+    }
+}
+
 impl<const D: bool, W: Copy + Add<Output = W> + Ord> WeightedMatrixGraph<D, W> {
     /// Floyd-Warshall algorythm
     ///
-    /// Calculate distances for every pair of nodes on graph
+    /// Calculate distances for every pair of nodes on graph.
+    /// The diagonal is the length of the shortest cycle through each node, or `None` if there is none.
     ///
     /// See https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
     pub fn floyd_warshall(&self) -> Vec<Vec<Option<W>>> {
-        let mut dist = self.raw_graph.clone();
-
-        for (k, i, j) in iproduct!(0..self.len(), 0..self.len(), 0..self.len()) {
-            if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
-                if dist[i][j].is_none() || dist[i][j].unwrap() > a + b {
-                    dist[i][j] = Some(a + b);
-                }
-            }
-        }
-
-        dist
+        crate::algorythm::floyd_warshall::floyd_warshall(self)
     }
 }
+
+#[test]
+fn graph_trait_test() {
+    use crate::algorythm::{bfs::bfs, dijkstra::dijkstra};
+
+    let edges = [(0, 1), (1, 2), (1, 3), (0, 4), (4, 3), (3, 5)];
+    let weighted = edges.map(|(u, v)| (u, v, 1usize));
+
+    let dul = DULGraph::from_edges(7, &edges);
+    let dum = DUMGraph::from_edges(7, &edges);
+    let dwl = DWLGraph::from_edges(7, &weighted);
+    let dwm = DWMGraph::from_edges(7, &weighted);
+
+    let expected = vec![Some(0), Some(1), Some(2), Some(2), Some(1), Some(3), None];
+    assert_eq!(bfs(&dul, 0), expected);
+    assert_eq!(bfs(&dum, 0), expected);
+    assert_eq!(bfs(&dwl, 0), expected);
+    assert_eq!(bfs(&dwm, 0), expected);
+    assert_eq!(dijkstra(&dul, 0), expected);
+    assert_eq!(dijkstra(&dum, 0), expected);
+    assert_eq!(dijkstra(&dwl, 0), expected);
+    assert_eq!(dijkstra(&dwm, 0), expected);
+
+    let uum = UUMGraph::from_edges(7, &edges);
+    let uwl = UWLGraph::from_edges(7, &weighted);
+    let uum_dist = uum.floyd_warshall();
+    let uwm_dist = UWMGraph::from_edges(7, &weighted).floyd_warshall();
+    for u in 0..7 {
+        let mut expected = bfs(&uwl, u);
+        assert_eq!(uum_dist[u], expected);
+
+        // the diagonal of the weighted one is the shortest cycle,
+        // going back and forth along an undirected edge
+        expected[u] = if u == 6 { None } else { Some(2) };
+        assert_eq!(uwm_dist[u], expected);
+    }
+    assert!((0..7).all(|u| dwm.floyd_warshall()[u][u].is_none()));
+
+    assert!(dul.is_directed());
+    assert!(!uwl.is_directed());
+    assert_eq!(dum.neighbors(1).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(uwl.neighbors(3).collect::<Vec<_>>(), vec![1, 4, 5]);
+}
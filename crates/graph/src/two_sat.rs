@@ -0,0 +1,200 @@
+use crate::DULGraph;
+
+/// 2-SAT solver
+///
+/// Decides whether a conjunction of clauses `(x_i = f) ∨ (x_j = g)` is satisfiable,
+/// reducing it to strongly connected components of the implication graph.
+///
+/// See https://en.wikipedia.org/wiki/2-satisfiability
+///
+/// Complexity: `O(n + m)`
+///
+/// ```
+/// use graph::two_sat::TwoSat;
+///
+/// let mut ts = TwoSat::new(3);
+/// ts.add_clause(0, true, 1, true);
+/// ts.xor(1, 2);
+/// ts.implies(2, true, 0, false);
+/// ts.set(0, false);
+/// assert_eq!(ts.satisfiable(), Some(vec![false, true, false]));
+///
+/// ts.set(1, false);
+/// assert_eq!(ts.satisfiable(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TwoSat {
+    len: usize,
+    /// The number of variables including the auxiliary ones
+    variables: usize,
+    /// Literal `x_i = f` is numbered `2 i + f`
+    clauses: Vec<(usize, usize)>,
+}
+
+impl TwoSat {
+    pub fn new(n: usize) -> Self {
+        Self {
+            len: n,
+            variables: n,
+            clauses: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn literal(i: usize, f: bool) -> usize {
+        2 * i + f as usize
+    }
+
+    /// Adds a clause of two literals, which may be auxiliary
+    fn push_clause(&mut self, a: usize, b: usize) {
+        self.clauses.push((a, b));
+    }
+
+    /// Adds a clause `(x_i = f) ∨ (x_j = g)`
+    pub fn add_clause(&mut self, i: usize, f: bool, j: usize, g: bool) {
+        assert!(i < self.len && j < self.len, "variable out of range");
+        self.push_clause(Self::literal(i, f), Self::literal(j, g));
+    }
+
+    /// Adds a constraint `x_i = f`
+    pub fn set(&mut self, i: usize, f: bool) {
+        self.add_clause(i, f, i, f);
+    }
+
+    /// Adds a constraint `(x_i = f) → (x_j = g)`
+    pub fn implies(&mut self, i: usize, f: bool, j: usize, g: bool) {
+        self.add_clause(i, !f, j, g);
+    }
+
+    /// Adds a constraint `x_i ≠ x_j`
+    pub fn xor(&mut self, i: usize, j: usize) {
+        self.add_clause(i, true, j, true);
+        self.add_clause(i, false, j, false);
+    }
+
+    /// Adds a constraint `x_i = x_j`
+    pub fn equal(&mut self, i: usize, j: usize) {
+        self.add_clause(i, true, j, false);
+        self.add_clause(i, false, j, true);
+    }
+
+    /// Adds a constraint that at most one of `x_i = f` holds for `(i, f)` in `literals`
+    ///
+    /// It uses `|literals|` auxiliary variables for prefixes instead of `O(k^2)` clauses.
+    pub fn at_most_one(&mut self, literals: &[(usize, bool)]) {
+        assert!(
+            literals.iter().all(|&(i, _)| i < self.len),
+            "variable out of range"
+        );
+
+        // s_k = (one of the first k + 1 literals holds)
+        let prefix = self.variables;
+        self.variables += literals.len();
+
+        for (k, &(i, f)) in literals.iter().enumerate() {
+            let x = Self::literal(i, f);
+            let s = Self::literal(prefix + k, true);
+            // x → s_k
+            self.push_clause(x ^ 1, s);
+            if k > 0 {
+                // s_{k-1} → s_k, s_{k-1} → ¬x
+                let t = s - 2;
+                self.push_clause(t ^ 1, s);
+                self.push_clause(t ^ 1, x ^ 1);
+            }
+        }
+    }
+
+    /// Returns an assignment satisfying all the constraints if any
+    pub fn satisfiable(&self) -> Option<Vec<bool>> {
+        // (a ∨ b) is equivalent to (¬a → b) ∧ (¬b → a)
+        let mut g = DULGraph::new(2 * self.variables);
+        for &(a, b) in self.clauses.iter() {
+            g.add_edge(a ^ 1, b);
+            g.add_edge(b ^ 1, a);
+        }
+
+        let scc = g.strongly_connected_components();
+        let mut assignment = (0..self.variables)
+            .map(|i| {
+                let (f, t) = (scc.id(2 * i), scc.id(2 * i + 1));
+                // the literal later in topological order is chosen
+                (f != t).then_some(f < t)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        assignment.truncate(self.len);
+        Some(assignment)
+    }
+}
+
+#[test]
+fn two_sat_test() {
+    use rand::Rng;
+
+    type Constraint = Box<dyn Fn(&[bool]) -> bool>;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..500 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..12);
+        let mut ts = TwoSat::new(n);
+        let mut constraints: Vec<Constraint> = vec![];
+
+        for _ in 0..m {
+            let i = rng.gen_range(0..n);
+            let j = rng.gen_range(0..n);
+            let f = rng.gen_bool(0.5);
+            let g = rng.gen_bool(0.5);
+            match rng.gen_range(0..5) {
+                0 => {
+                    ts.add_clause(i, f, j, g);
+                    constraints.push(Box::new(move |x| x[i] == f || x[j] == g));
+                }
+                1 => {
+                    ts.implies(i, f, j, g);
+                    constraints.push(Box::new(move |x| x[i] != f || x[j] == g));
+                }
+                2 => {
+                    ts.xor(i, j);
+                    constraints.push(Box::new(move |x| x[i] != x[j]));
+                }
+                3 => {
+                    ts.equal(i, j);
+                    constraints.push(Box::new(move |x| x[i] == x[j]));
+                }
+                _ => {
+                    let literals = (0..rng.gen_range(0..4))
+                        .map(|_| (rng.gen_range(0..n), rng.gen_bool(0.5)))
+                        .collect::<Vec<_>>();
+                    ts.at_most_one(&literals);
+                    constraints.push(Box::new(move |x| {
+                        literals.iter().filter(|&&(i, f)| x[i] == f).count() <= 1
+                    }));
+                }
+            }
+        }
+
+        let check = |x: &[bool]| constraints.iter().all(|c| c(x));
+        let brute_force = (0..1 << n)
+            .any(|bits: usize| check(&(0..n).map(|i| bits >> i & 1 == 1).collect::<Vec<_>>()));
+        match ts.satisfiable() {
+            Some(x) => {
+                assert_eq!(x.len(), n);
+                assert!(check(&x));
+            }
+            None => assert!(!brute_force),
+        }
+    }
+
+    // auxiliary variables are not exposed
+    let mut ts = TwoSat::new(2);
+    ts.at_most_one(&[(0, true), (1, true)]);
+    assert!(std::panic::catch_unwind(move || ts.add_clause(2, true, 0, true)).is_err());
+}
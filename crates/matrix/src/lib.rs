@@ -0,0 +1,468 @@
+use modint::StaticModInt;
+use num_traits::{One, Zero};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Dense matrix over ring-like scalars, like `i64`, `f64` or `StaticModInt`
+///
+/// Gaussian elimination based methods (`determinant`, `rank`, `inverse`, `solve`)
+/// are only available for scalars implementing [`Field`].
+///
+/// ```
+/// use matrix::Matrix;
+/// use modint::ModInt998244353 as Mint;
+///
+/// // Fibonacci numbers by matrix exponentiation
+/// let one = Mint::new(1);
+/// let a = Matrix::from(vec![vec![one, one], vec![one, Mint::new(0)]]);
+/// assert_eq!(a.pow(90)[(0, 1)].get(), 2880067194370816120 % 998244353);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    buffer: Vec<T>,
+}
+
+/// Scalars with exact division by any nonzero element, like `StaticModInt` with a prime modulus
+///
+/// Gaussian elimination divides by the pivots and compares them with zero exactly,
+/// so integers like `i64` and floating-point numbers like `f64` are not supported.
+pub trait Field:
+    Copy + PartialEq + Zero + One + Neg<Output = Self> + Sub<Output = Self> + Div<Output = Self>
+{
+}
+
+impl<const M: u64> Field for StaticModInt<M> {}
+
+impl<T> Matrix<T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.buffer[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.buffer[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+impl<T: Copy + Zero> Matrix<T> {
+    /// Returns zero matrix
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            buffer: vec![T::zero(); rows * cols],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut res = Self::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                res[(j, i)] = self[(i, j)];
+            }
+        }
+        res
+    }
+}
+
+impl<T: Copy + Zero + One> Matrix<T> {
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            res[(i, i)] = T::one();
+        }
+        res
+    }
+
+    /// Returns `self^exp` by binary exponentiation
+    ///
+    /// Complexity: `O(n^3 log exp)`
+    pub fn pow(&self, mut exp: u64) -> Self {
+        assert!(self.is_square(), "matrix must be square");
+
+        let mut res = Self::identity(self.rows);
+        let mut cur = self.clone();
+        while exp != 0 {
+            if exp & 1 != 0 {
+                res = &res * &cur;
+            }
+            cur = &cur * &cur;
+            exp >>= 1;
+        }
+        res
+    }
+
+    /// Returns the product of `self` and column vector `v`
+    pub fn apply(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.cols, v.len());
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+            })
+            .collect()
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Transforms `self` into reduced row echelon form by Gauss–Jordan elimination,
+    /// only looking at the first `width` columns for pivots
+    ///
+    /// Returns the pivot columns and the determinant factor of the row operations
+    fn row_reduce(&mut self, width: usize) -> (Vec<usize>, T) {
+        let mut pivots = vec![];
+        let mut factor = T::one();
+        for col in 0..width {
+            let r = pivots.len();
+            let pivot = match (r..self.rows).find(|&i| !self[(i, col)].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            if pivot != r {
+                for j in 0..self.cols {
+                    self.buffer.swap(pivot * self.cols + j, r * self.cols + j);
+                }
+                factor = -factor;
+            }
+
+            let p = self[(r, col)];
+            factor = factor * p;
+            for x in self.row_mut(r) {
+                *x = *x / p;
+            }
+
+            for i in (0..self.rows).filter(|&i| i != r) {
+                let c = self[(i, col)];
+                if c.is_zero() {
+                    continue;
+                }
+                for j in col..self.cols {
+                    let x = self[(r, j)];
+                    self[(i, j)] = self[(i, j)] - c * x;
+                }
+            }
+
+            pivots.push(col);
+            if pivots.len() == self.rows {
+                break;
+            }
+        }
+
+        (pivots, factor)
+    }
+
+    /// Complexity: `O(n^3)`
+    pub fn determinant(&self) -> T {
+        assert!(self.is_square(), "matrix must be square");
+
+        let mut a = self.clone();
+        let (pivots, factor) = a.row_reduce(self.cols);
+        if pivots.len() == self.rows {
+            factor
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Complexity: `O(n^3)`
+    pub fn rank(&self) -> usize {
+        let mut a = self.clone();
+        a.row_reduce(self.cols).0.len()
+    }
+
+    /// Returns the inverse matrix, if `self` is regular
+    ///
+    /// Complexity: `O(n^3)`
+    pub fn inverse(&self) -> Option<Self> {
+        assert!(self.is_square(), "matrix must be square");
+
+        let n = self.rows;
+        let mut a = Self::new(n, n * 2);
+        for i in 0..n {
+            a.row_mut(i)[..n].copy_from_slice(self.row(i));
+            a[(i, n + i)] = T::one();
+        }
+
+        if a.row_reduce(n).0.len() < n {
+            return None;
+        }
+
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            res.row_mut(i).copy_from_slice(&a.row(i)[n..]);
+        }
+        Some(res)
+    }
+
+    /// Solves linear equations `self * x = b`
+    ///
+    /// Returns a particular solution and a basis of the kernel of `self`,
+    /// or `None` if there is no solution.
+    /// All solutions are the particular one plus any linear combinations of the basis.
+    ///
+    /// Complexity: `O(n^2 m)`
+    #[allow(clippy::type_complexity)]
+    pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        assert_eq!(self.rows, b.len());
+
+        let (n, m) = (self.rows, self.cols);
+        let mut a = Self::new(n, m + 1);
+        for i in 0..n {
+            a.row_mut(i)[..m].copy_from_slice(self.row(i));
+            a[(i, m)] = b[i];
+        }
+
+        let (pivots, _) = a.row_reduce(m);
+        if (pivots.len()..n).any(|i| !a[(i, m)].is_zero()) {
+            return None;
+        }
+
+        let mut solution = vec![T::zero(); m];
+        for (r, &c) in pivots.iter().enumerate() {
+            solution[c] = a[(r, m)];
+        }
+
+        let mut is_pivot = vec![false; m];
+        for &c in pivots.iter() {
+            is_pivot[c] = true;
+        }
+        let basis = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![T::zero(); m];
+                v[f] = T::one();
+                for (r, &c) in pivots.iter().enumerate() {
+                    v[c] = -a[(r, f)];
+                }
+                v
+            })
+            .collect();
+
+        Some((solution, basis))
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(v: Vec<Vec<T>>) -> Self {
+        let rows = v.len();
+        let cols = v.first().map_or(0, |x| x.len());
+        assert!(
+            v.iter().all(|x| x.len() == cols),
+            "rows must have the same length"
+        );
+
+        Self {
+            rows,
+            cols,
+            buffer: v.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &self.buffer[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &mut self.buffer[i * self.cols + j]
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Add for Matrix<T> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Matrix<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+        for (x, y) in self.buffer.iter_mut().zip(rhs.buffer) {
+            *x = *x + y;
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Sub for Matrix<T> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+        for (x, y) in self.buffer.iter_mut().zip(rhs.buffer) {
+            *x = *x - y;
+        }
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Complexity: `O(n m l)`
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows);
+
+        let mut res = Matrix::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a.is_zero() {
+                    continue;
+                }
+                for (x, &b) in res.row_mut(i).iter_mut().zip(rhs.row(k)) {
+                    *x = *x + a * b;
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul for Matrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> MulAssign for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Matrix<T> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        for x in self.buffer.iter_mut() {
+            *x = *x * rhs;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+    use modint::ModInt998244353 as Mint;
+    use num_traits::{One, Zero};
+    use rand::Rng;
+
+    fn random_matrix(rows: usize, cols: usize, range: u64) -> Matrix<Mint> {
+        let mut rng = rand::thread_rng();
+        Matrix::from(
+            (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| Mint::new(rng.gen_range(0..range)))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>(),
+        )
+    }
+
+    /// Determinant by cofactor expansion
+    fn naive_determinant(a: &Matrix<Mint>) -> Mint {
+        let n = a.rows();
+        if n == 0 {
+            return Mint::one();
+        }
+        (0..n)
+            .map(|j| {
+                let minor = Matrix::from(
+                    (1..n)
+                        .map(|i| (0..n).filter(|&k| k != j).map(|k| a[(i, k)]).collect())
+                        .collect::<Vec<Vec<_>>>(),
+                );
+                let x = a[(0, j)] * naive_determinant(&minor);
+                if j % 2 == 0 {
+                    x
+                } else {
+                    -x
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn pow_test() {
+        let a = random_matrix(5, 5, 998244353);
+        let mut expected = Matrix::identity(5);
+        for k in 0..10 {
+            assert_eq!(a.pow(k), expected);
+            expected *= a.clone();
+        }
+    }
+
+    #[test]
+    fn determinant_inverse_test() {
+        for n in 0..6 {
+            for _ in 0..20 {
+                // small entries to make singular matrices likely
+                let a = random_matrix(n, n, 3);
+                let det = a.determinant();
+                assert_eq!(det, naive_determinant(&a));
+                assert_eq!(a.rank() == n, !det.is_zero());
+
+                match a.inverse() {
+                    Some(b) => {
+                        assert_eq!(&a * &b, Matrix::identity(n));
+                        assert_eq!(&b * &a, Matrix::identity(n));
+                    }
+                    None => assert!(det.is_zero()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_test() {
+        for _ in 0..100 {
+            let a = random_matrix(4, 6, 3);
+            let x = random_matrix(6, 1, 3);
+            let b = (&a * &x).transpose().row(0).to_vec();
+
+            let (solution, basis) = a.solve(&b).unwrap();
+            assert_eq!(a.apply(&solution), b);
+            assert_eq!(basis.len(), 6 - a.rank());
+            for v in basis {
+                assert!(a.apply(&v).iter().all(|x| x.is_zero()));
+            }
+        }
+
+        let a = Matrix::from(vec![vec![Mint::one()], vec![Mint::one()]]);
+        assert!(a.solve(&[Mint::one(), Mint::zero()]).is_none());
+    }
+}
@@ -0,0 +1,355 @@
+use crate::StaticModInt;
+use num_traits::{Inv, One, Pow, Zero};
+
+/// `g` must be a primitive root modulo `M`
+fn ntt<const M: u64>(a: &mut [StaticModInt<M>], g: StaticModInt<M>, inverse: bool) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = g.pow((M - 1) / len as u64);
+        let w = if inverse { w.inv() } else { w };
+
+        let mut powers = Vec::with_capacity(len / 2);
+        let mut cur = StaticModInt::one();
+        for _ in 0..len / 2 {
+            powers.push(cur);
+            cur *= w;
+        }
+
+        for chunk in a.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &wk) in lo.iter_mut().zip(hi.iter_mut()).zip(powers.iter()) {
+                let u = *x;
+                let v = *y * wk;
+                *x = u + v;
+                *y = u - v;
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        let n_inv = StaticModInt::<M>::new(n).inv();
+        for x in a.iter_mut() {
+            *x *= n_inv;
+        }
+    }
+}
+
+fn convolution_naive<const M: u64>(
+    a: &[StaticModInt<M>],
+    b: &[StaticModInt<M>],
+) -> Vec<StaticModInt<M>> {
+    let mut res = vec![StaticModInt::zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    res
+}
+
+/// # Convolution
+///
+/// Returns `c` where `c[k] = Σ a[i] * b[k - i]`,
+/// that is, the coefficients of the product of two polynomials.
+///
+/// `M` must be an NTT-friendly prime like 998244353,
+/// and `2^⌈log2(|a| + |b| - 1)⌉` must divide `M - 1`.
+/// For small inputs, naive multiplication is used instead.
+///
+/// See https://en.wikipedia.org/wiki/Number-theoretic_transform
+///
+/// Complexity: `O((n + m) log (n + m))`
+///
+/// ```
+/// use modint::{convolution::convolution, ModInt998244353};
+///
+/// let a = vec![1, 2, 3].into_iter().map(ModInt998244353::new).collect::<Vec<_>>();
+/// let b = vec![4, 5].into_iter().map(ModInt998244353::new).collect::<Vec<_>>();
+/// let c = convolution(&a, &b);
+/// assert_eq!(c.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![4, 13, 22, 15]);
+/// ```
+pub fn convolution<const M: u64>(
+    a: &[StaticModInt<M>],
+    b: &[StaticModInt<M>],
+) -> Vec<StaticModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if a.len().min(b.len()) <= 60 {
+        return convolution_naive(a, b);
+    }
+
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    assert!(
+        (M - 1).is_multiple_of(size as u64),
+        "modulus {} does not support convolution of length {}",
+        M,
+        len
+    );
+
+    let mut fa = a.to_vec();
+    fa.resize(size, StaticModInt::zero());
+    let mut fb = b.to_vec();
+    fb.resize(size, StaticModInt::zero());
+
+    let g = StaticModInt::new(StaticModInt::<M>::PRIMITIVE_ROOT);
+    ntt(&mut fa, g, false);
+    ntt(&mut fb, g, false);
+    for (x, y) in fa.iter_mut().zip(fb) {
+        *x *= y;
+    }
+    ntt(&mut fa, g, true);
+
+    fa.truncate(len);
+    fa
+}
+
+const MOD1: u64 = 754974721; // 2^24 * 45 + 1
+const MOD2: u64 = 167772161; // 2^25 * 5 + 1
+const MOD3: u64 = 469762049; // 2^26 * 7 + 1
+
+/// Convolutes `a` and `b` modulo an NTT-friendly prime `P`
+fn convolution_i64_by<const P: u64>(a: &[i64], b: &[i64]) -> Vec<StaticModInt<P>> {
+    let a = a.iter().map(|&x| StaticModInt::new(x)).collect::<Vec<_>>();
+    let b = b.iter().map(|&x| StaticModInt::new(x)).collect::<Vec<_>>();
+    convolution(&a, &b)
+}
+
+/// # Convolution with arbitrary modulus
+///
+/// Same as [`convolution`], but `M` need not be NTT-friendly, like 1000000007.
+/// Convolutes in three NTT-friendly primes and restores the result
+/// by Garner's algorithm.
+///
+/// `|a| + |b| - 1` must be at most `2^24`.
+/// The result is exact only while every coefficient before reduction modulo `M`
+/// is less than `MOD1 MOD2 MOD3 ≈ 2^85.6`, that is,
+/// `min(|a|, |b|) (M - 1)^2 < 754974721 * 167772161 * 469762049`.
+/// It holds for `M < 2^31` with any length allowed above,
+/// and is checked in debug builds.
+/// For small inputs, naive multiplication is used instead.
+///
+/// See https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+///
+/// Complexity: `O((n + m) log (n + m))`
+///
+/// ```
+/// use modint::{convolution::convolution_mod, ModInt1000000007};
+///
+/// let a = vec![1000000006, 2].into_iter().map(ModInt1000000007::new).collect::<Vec<_>>();
+/// let b = vec![1000000006, 3].into_iter().map(ModInt1000000007::new).collect::<Vec<_>>();
+/// let c = convolution_mod(&a, &b);
+/// assert_eq!(c.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![1, 1000000002, 6]);
+/// ```
+pub fn convolution_mod<const M: u64>(
+    a: &[StaticModInt<M>],
+    b: &[StaticModInt<M>],
+) -> Vec<StaticModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if a.len().min(b.len()) <= 60 {
+        return convolution_naive(a, b);
+    }
+
+    debug_assert!(
+        ((M - 1) as u128)
+            .checked_mul((M - 1) as u128)
+            .and_then(|x| x.checked_mul(a.len().min(b.len()) as u128))
+            .is_some_and(|x| x < MOD1 as u128 * MOD2 as u128 * MOD3 as u128),
+        "coefficients of the convolution may exceed MOD1 MOD2 MOD3"
+    );
+
+    let a = a.iter().map(|x| x.get() as i64).collect::<Vec<_>>();
+    let b = b.iter().map(|x| x.get() as i64).collect::<Vec<_>>();
+    let c1 = convolution_i64_by::<MOD1>(&a, &b);
+    let c2 = convolution_i64_by::<MOD2>(&a, &b);
+    let c3 = convolution_i64_by::<MOD3>(&a, &b);
+
+    let m1_inv_m2 = StaticModInt::<MOD2>::new(MOD1).inv();
+    let m1m2_inv_m3 = (StaticModInt::<MOD3>::new(MOD1) * MOD2).inv();
+    let m1_m = MOD1 % M;
+    let m1m2_m = (MOD1 as u128 * MOD2 as u128 % M as u128) as u64;
+
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((x1, x2), x3)| {
+            let t1 = (x2 - x1.get()) * m1_inv_m2;
+            let t2 = (x3 - x1.get() - StaticModInt::<MOD3>::new(MOD1) * t1.get()) * m1m2_inv_m3;
+            let value = (x1.get() as u128
+                + m1_m as u128 * t1.get() as u128
+                + m1m2_m as u128 * t2.get() as u128)
+                % M as u128;
+            StaticModInt::new(value as u64)
+        })
+        .collect()
+}
+
+/// # Convolution over integers
+///
+/// Returns `c` where `c[k] = Σ a[i] * b[k - i]` exactly,
+/// as long as every `c[k]` fits in `i64`.
+///
+/// `|a| + |b| - 1` must be at most `2^24`.
+///
+/// Complexity: `O((n + m) log (n + m))`
+///
+/// ```
+/// use modint::convolution::convolution_i64;
+///
+/// let c = convolution_i64(&[-1, 1_000_000_000_000], &[1_000_000, -2]);
+/// assert_eq!(c, vec![-1_000_000, 1_000_000_000_000_000_002, -2_000_000_000_000]);
+/// ```
+pub fn convolution_i64(a: &[i64], b: &[i64]) -> Vec<i64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    const M2M3: u64 = MOD2.wrapping_mul(MOD3);
+    const M1M3: u64 = MOD1.wrapping_mul(MOD3);
+    const M1M2: u64 = MOD1.wrapping_mul(MOD2);
+    const M1M2M3: u64 = M1M2.wrapping_mul(MOD3);
+    const OFFSET: [u64; 5] = [0, 0, M1M2M3, M1M2M3.wrapping_mul(2), M1M2M3.wrapping_mul(3)];
+
+    let i1 = (StaticModInt::<MOD1>::new(MOD2) * MOD3).inv().get();
+    let i2 = (StaticModInt::<MOD2>::new(MOD1) * MOD3).inv().get();
+    let i3 = (StaticModInt::<MOD3>::new(MOD1) * MOD2).inv().get();
+
+    let c1 = convolution_i64_by::<MOD1>(a, b);
+    let c2 = convolution_i64_by::<MOD2>(a, b);
+    let c3 = convolution_i64_by::<MOD3>(a, b);
+
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((x1, x2), x3)| {
+            let mut x = 0u64;
+            x = x.wrapping_add((x1.get() * i1 % MOD1).wrapping_mul(M2M3));
+            x = x.wrapping_add((x2.get() * i2 % MOD2).wrapping_mul(M1M3));
+            x = x.wrapping_add((x3.get() * i3 % MOD3).wrapping_mul(M1M2));
+
+            // x is now congruent to the answer modulo 2^64,
+            // but may differ from it by a multiple of M1M2M3
+            let diff = x1.get() as i64 - (x as i64).rem_euclid(MOD1 as i64);
+            let diff = diff.rem_euclid(MOD1 as i64);
+            x.wrapping_sub(OFFSET[diff as usize % 5]) as i64
+        })
+        .collect()
+}
+
+#[test]
+fn convolution_test() {
+    use crate::ModInt998244353;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let n = rng.gen_range(1..300);
+        let m = rng.gen_range(1..300);
+        let a = (0..n)
+            .map(|_| ModInt998244353::new(rng.gen_range(0..998244353)))
+            .collect::<Vec<_>>();
+        let b = (0..m)
+            .map(|_| ModInt998244353::new(rng.gen_range(0..998244353)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(convolution(&a, &b), convolution_naive(&a, &b));
+    }
+
+    assert!(convolution::<998244353>(&[], &[ModInt998244353::one()]).is_empty());
+}
+
+#[test]
+fn convolution_mod_test() {
+    use crate::ModInt1000000007;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let n = rng.gen_range(1..300);
+        let m = rng.gen_range(1..300);
+        let a = (0..n)
+            .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+            .collect::<Vec<_>>();
+        let b = (0..m)
+            .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(convolution_mod(&a, &b), convolution_naive(&a, &b));
+    }
+
+    // M near 2^31 with the largest values, where the coefficients are close to the bound
+    const M: u64 = (1 << 31) - 1;
+    let (n, m) = (3000, 2000);
+    assert!(
+        n.min(m) as u128 * ((M - 1) as u128).pow(2) < MOD1 as u128 * MOD2 as u128 * MOD3 as u128
+    );
+    let a = (0..n)
+        .map(|i| {
+            StaticModInt::<M>::new(if i % 3 == 0 {
+                M - 1
+            } else {
+                rng.gen_range(0..M)
+            })
+        })
+        .collect::<Vec<_>>();
+    let b = (0..m)
+        .map(|i| {
+            StaticModInt::<M>::new(if i % 2 == 0 {
+                M - 1
+            } else {
+                rng.gen_range(0..M)
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(convolution_mod(&a, &b), convolution_naive(&a, &b));
+    let full = vec![StaticModInt::<M>::new(M - 1); n];
+    assert_eq!(
+        convolution_mod(&full, &full),
+        convolution_naive(&full, &full)
+    );
+}
+
+#[test]
+fn convolution_i64_test() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let n = rng.gen_range(1..300);
+        let m = rng.gen_range(1..300);
+        let a = (0..n)
+            .map(|_| rng.gen_range(-1_000_000_000..=1_000_000_000i64))
+            .collect::<Vec<_>>();
+        let b = (0..m)
+            .map(|_| rng.gen_range(-1_000_000..=1_000_000i64))
+            .collect::<Vec<_>>();
+
+        let mut c = vec![0i64; n + m - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        assert_eq!(convolution_i64(&a, &b), c);
+    }
+}
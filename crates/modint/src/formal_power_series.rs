@@ -0,0 +1,458 @@
+use crate::{convolution::convolution, StaticModInt};
+use num_traits::{Inv, One, Pow, Zero};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+
+/// # Formal power series
+///
+/// `a[0] + a[1] x + a[2] x^2 + ...` over `StaticModInt<M>`
+///
+/// Multiplication is done by NTT,
+/// so `M` must be an NTT-friendly prime like 998244353.
+///
+/// Methods taking `n` return the first `n` coefficients of the result.
+///
+/// ```
+/// use modint::{formal_power_series::FormalPowerSeries, ModInt998244353};
+///
+/// // 1 / (1 - x) = 1 + x + x^2 + ...
+/// let f = FormalPowerSeries::<998244353>::from(vec![1, -1]);
+/// assert_eq!(f.inv(4), FormalPowerSeries::from(vec![1, 1, 1, 1]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FormalPowerSeries<const M: u64>(pub Vec<StaticModInt<M>>);
+
+impl<const M: u64> FormalPowerSeries<M> {
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the coefficient of `x^i`, which is zero out of range
+    pub fn coefficient(&self, i: usize) -> StaticModInt<M> {
+        self.0.get(i).copied().unwrap_or_else(StaticModInt::zero)
+    }
+
+    /// Returns the first `n` coefficients, padding zeros if needed
+    pub fn truncated(&self, n: usize) -> Self {
+        let mut res = self.0[..n.min(self.len())].to_vec();
+        res.resize(n, StaticModInt::zero());
+        Self(res)
+    }
+
+    /// Removes trailing zeros
+    pub fn shrink(&mut self) {
+        while self.0.last().is_some_and(|x| x.is_zero()) {
+            self.0.pop();
+        }
+    }
+
+    /// Returns the degree of polynomial, or `None` for zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.0.iter().rposition(|x| !x.is_zero())
+    }
+
+    /// Evaluates the polynomial at `x` by Horner's method
+    ///
+    /// Complexity: `O(n)`
+    pub fn evaluate(&self, x: StaticModInt<M>) -> StaticModInt<M> {
+        self.0
+            .iter()
+            .rev()
+            .fold(StaticModInt::zero(), |acc, &a| acc * x + a)
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &a)| a * i)
+                .collect(),
+        )
+    }
+
+    /// Returns the integral whose constant term is zero
+    pub fn integral(&self) -> Self {
+        let mut inverses = vec![StaticModInt::<M>::one(); self.len() + 1];
+        for i in 2..=self.len() {
+            inverses[i] = -inverses[M as usize % i] * (M as usize / i);
+        }
+
+        let mut res = vec![StaticModInt::zero()];
+        res.extend(self.0.iter().zip(&inverses[1..]).map(|(&a, &inv)| a * inv));
+        Self(res)
+    }
+
+    /// Returns `g` where `f * g ≡ 1 (mod. x^n)`
+    ///
+    /// `self[0]` must not be zero.
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn inv(&self, n: usize) -> Self {
+        assert!(
+            !self.coefficient(0).is_zero(),
+            "constant term must not be zero"
+        );
+
+        let mut g = Self(vec![self.0[0].inv()]);
+        let mut len = 1;
+        while len < n {
+            len *= 2;
+            let fg = (self.truncated(len) * g.clone()).truncated(len);
+            g = (g * (Self(vec![StaticModInt::new(2)]) - fg)).truncated(len);
+        }
+        g.truncated(n)
+    }
+
+    /// Returns `log f (mod. x^n)`
+    ///
+    /// `self[0]` must be one.
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn log(&self, n: usize) -> Self {
+        assert!(self.coefficient(0).is_one(), "constant term must be one");
+
+        (self.derivative() * self.inv(n))
+            .truncated(n.saturating_sub(1))
+            .integral()
+            .truncated(n)
+    }
+
+    /// Returns `exp f (mod. x^n)`
+    ///
+    /// `self[0]` must be zero.
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(self.coefficient(0).is_zero(), "constant term must be zero");
+
+        let mut g = Self(vec![StaticModInt::one()]);
+        let mut len = 1;
+        while len < n {
+            len *= 2;
+            let mut h = self.truncated(len) - g.log(len);
+            h.0[0] += 1;
+            g = (g * h).truncated(len);
+        }
+        g.truncated(n)
+    }
+
+    /// Returns `f^k (mod. x^n)`
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Self(vec![StaticModInt::one()]).truncated(n);
+        }
+
+        let i = match self.0.iter().position(|x| !x.is_zero()) {
+            Some(i) if (i as u128) * (k as u128) < n as u128 => i,
+            _ => return Self(vec![]).truncated(n),
+        };
+
+        let shift = i * k as usize;
+        let c = self.0[i];
+        let f = Self(self.0[i..].to_vec()) * c.inv();
+        let g = (f.log(n - shift) * StaticModInt::new(k % M)).exp(n - shift) * c.pow(k);
+
+        let mut res = vec![StaticModInt::zero(); shift];
+        res.extend(g.0);
+        Self(res)
+    }
+
+    /// Returns `g` where `g^2 ≡ f (mod. x^n)`, if exists
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let i = match self.0.iter().position(|x| !x.is_zero()) {
+            Some(i) => i,
+            None => return Some(Self(vec![]).truncated(n)),
+        };
+        if i % 2 != 0 {
+            return None;
+        }
+        if i / 2 >= n {
+            return Some(Self(vec![]).truncated(n));
+        }
+
+        let f = Self(self.0[i..].to_vec());
+        let m = n - i / 2;
+        let two_inv = StaticModInt::<M>::new(2).inv();
+        let mut g = Self(vec![f.0[0].sqrt()?]);
+        let mut len = 1;
+        while len < m {
+            len *= 2;
+            g = ((f.truncated(len) * g.inv(len)).truncated(len) + g) * two_inv;
+        }
+
+        let mut res = vec![StaticModInt::zero(); i / 2];
+        res.extend(g.truncated(m).0);
+        Some(Self(res))
+    }
+
+    /// Returns the pair of quotient and remainder of polynomial division
+    ///
+    /// Complexity: `O(n log n)`
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let m = rhs.degree().expect("attempt to divide by zero polynomial") + 1;
+        let mut f = self.clone();
+        f.shrink();
+        if f.len() < m {
+            return (Self::new(), f);
+        }
+
+        let k = f.len() - m + 1;
+        let mut rev_f = f.0.clone();
+        rev_f.reverse();
+        let mut rev_g = rhs.0[..m].to_vec();
+        rev_g.reverse();
+
+        let mut q = (Self(rev_f).truncated(k) * Self(rev_g).inv(k)).truncated(k);
+        q.0.reverse();
+        q.shrink();
+
+        let mut r = (f - q.clone() * rhs.truncated(m)).truncated(m - 1);
+        r.shrink();
+        (q, r)
+    }
+
+    /// Evaluates the polynomial at each point of `xs`
+    ///
+    /// Complexity: `O(n log^2 n)`
+    pub fn multipoint_evaluate(&self, xs: &[StaticModInt<M>]) -> Vec<StaticModInt<M>> {
+        if xs.is_empty() {
+            return vec![];
+        }
+
+        let size = xs.len().next_power_of_two();
+        let mut tree = vec![Self(vec![StaticModInt::one()]); size * 2];
+        for (i, &x) in xs.iter().enumerate() {
+            tree[size + i] = Self(vec![-x, StaticModInt::one()]);
+        }
+        for i in (1..size).rev() {
+            tree[i] = tree[i * 2].clone() * tree[i * 2 + 1].clone();
+        }
+
+        let mut rem = vec![Self::new(); size * 2];
+        rem[1] = self.div_rem(&tree[1]).1;
+        for i in 2..size + xs.len() {
+            rem[i] = rem[i / 2].div_rem(&tree[i]).1;
+        }
+
+        (0..xs.len())
+            .map(|i| rem[size + i].coefficient(0))
+            .collect()
+    }
+}
+
+impl<const M: u64> From<Vec<StaticModInt<M>>> for FormalPowerSeries<M> {
+    fn from(v: Vec<StaticModInt<M>>) -> Self {
+        Self(v)
+    }
+}
+
+impl<const M: u64> From<Vec<i64>> for FormalPowerSeries<M> {
+    fn from(v: Vec<i64>) -> Self {
+        Self(v.into_iter().map(StaticModInt::new).collect())
+    }
+}
+
+impl<const M: u64> Index<usize> for FormalPowerSeries<M> {
+    type Output = StaticModInt<M>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const M: u64> IndexMut<usize> for FormalPowerSeries<M> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const M: u64> Add for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const M: u64> AddAssign for FormalPowerSeries<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        if self.len() < rhs.len() {
+            self.0.resize(rhs.len(), StaticModInt::zero());
+        }
+        for (x, y) in self.0.iter_mut().zip(rhs.0) {
+            *x += y;
+        }
+    }
+}
+
+impl<const M: u64> Sub for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const M: u64> SubAssign for FormalPowerSeries<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.len() < rhs.len() {
+            self.0.resize(rhs.len(), StaticModInt::zero());
+        }
+        for (x, y) in self.0.iter_mut().zip(rhs.0) {
+            *x -= y;
+        }
+    }
+}
+
+impl<const M: u64> Neg for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.into_iter().map(|x| -x).collect())
+    }
+}
+
+impl<const M: u64> Mul for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(convolution(&self.0, &rhs.0))
+    }
+}
+
+impl<const M: u64> MulAssign for FormalPowerSeries<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) * rhs;
+    }
+}
+
+impl<const M: u64> Mul<StaticModInt<M>> for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: StaticModInt<M>) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<const M: u64> MulAssign<StaticModInt<M>> for FormalPowerSeries<M> {
+    fn mul_assign(&mut self, rhs: StaticModInt<M>) {
+        for x in self.0.iter_mut() {
+            *x *= rhs;
+        }
+    }
+}
+
+impl<const M: u64> Div for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<const M: u64> DivAssign for FormalPowerSeries<M> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).0;
+    }
+}
+
+impl<const M: u64> Rem for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<const M: u64> RemAssign for FormalPowerSeries<M> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).1;
+    }
+}
+
+#[cfg(test)]
+fn random_fps(n: usize) -> FormalPowerSeries<998244353> {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    FormalPowerSeries::from(
+        (0..n)
+            .map(|_| rng.gen_range(0..998244353))
+            .collect::<Vec<i64>>(),
+    )
+}
+
+#[test]
+fn inv_log_exp_test() {
+    let n = 200;
+    let mut f = random_fps(n);
+    f[0] = StaticModInt::one();
+
+    let g = f.inv(n);
+    assert_eq!(
+        (f.clone() * g).truncated(n),
+        FormalPowerSeries::from(vec![1]).truncated(n)
+    );
+
+    let g = f.log(n);
+    assert!(g[0].is_zero());
+    assert_eq!(g.exp(n), f);
+}
+
+#[test]
+fn pow_sqrt_test() {
+    let n = 100;
+    let mut f = random_fps(n);
+    f[0] = StaticModInt::zero();
+    f[1] = StaticModInt::zero();
+
+    let mut expected = FormalPowerSeries::from(vec![1]);
+    for k in 0..5 {
+        assert_eq!(f.pow(k, n), expected.truncated(n));
+        expected = (expected * f.clone()).truncated(n);
+    }
+    assert_eq!(f.pow(1 << 60, n), FormalPowerSeries::new().truncated(n));
+
+    let g = f.pow(2, n);
+    let h = g.sqrt(n).unwrap();
+    assert_eq!((h.clone() * h).truncated(n), g);
+    assert!(FormalPowerSeries::<998244353>::from(vec![0, 1])
+        .sqrt(3)
+        .is_none());
+}
+
+#[test]
+fn div_rem_evaluate_test() {
+    let f = random_fps(300);
+    let g = random_fps(120);
+    let (q, r) = f.div_rem(&g);
+    assert!(r.len() < g.len());
+    let mut h = q * g + r;
+    h.shrink();
+    assert_eq!(h, f);
+
+    let xs = random_fps(100).0;
+    let ys = f.multipoint_evaluate(&xs);
+    for (&x, y) in xs.iter().zip(ys) {
+        assert_eq!(f.evaluate(x), y);
+    }
+}
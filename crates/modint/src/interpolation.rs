@@ -0,0 +1,150 @@
+use crate::{formal_power_series::FormalPowerSeries, table::CombTable, StaticModInt};
+use num_traits::{Inv, One, Zero};
+
+/// # Lagrange interpolation
+///
+/// Returns `f(n)` where `f` is the polynomial of degree `d`
+/// given by its values `f(0), f(1), ..., f(d)`
+///
+/// `M` must be a prime greater than `d`.
+///
+/// See https://en.wikipedia.org/wiki/Lagrange_polynomial
+///
+/// Complexity: `O(d)`
+///
+/// ```
+/// use modint::{interpolation::lagrange_interpolation, ModInt998244353};
+///
+/// // 1^2 + 2^2 + ... + n^2 is a polynomial of degree 3
+/// let values = [0, 1, 5, 14].map(ModInt998244353::new);
+/// assert_eq!(lagrange_interpolation(&values, 100).get(), 338350);
+/// ```
+pub fn lagrange_interpolation<const M: u64>(values: &[StaticModInt<M>], n: u64) -> StaticModInt<M> {
+    if values.is_empty() {
+        return StaticModInt::zero();
+    }
+    if n < values.len() as u64 {
+        return values[n as usize];
+    }
+
+    let d = values.len() - 1;
+    let table = CombTable::<M>::new(d);
+    let n = StaticModInt::<M>::new(n % M);
+
+    // prefix[i] = (n - 0)(n - 1)...(n - i + 1), suffix[i] = (n - i)(n - i - 1)...(n - d)
+    let mut prefix = vec![StaticModInt::one(); d + 2];
+    for i in 0..=d {
+        prefix[i + 1] = prefix[i] * (n - i);
+    }
+    let mut suffix = vec![StaticModInt::one(); d + 2];
+    for i in (0..=d).rev() {
+        suffix[i] = suffix[i + 1] * (n - i);
+    }
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &y)| {
+            let term = y
+                * prefix[i]
+                * suffix[i + 1]
+                * table.factorial_inversed(i)
+                * table.factorial_inversed(d - i);
+            if (d - i).is_multiple_of(2) {
+                term
+            } else {
+                -term
+            }
+        })
+        .sum()
+}
+
+/// Returns the coefficients of the polynomial `f` of degree `|xs| - 1`
+/// where `f(xs[i]) = ys[i]` for each `i`
+///
+/// `xs` must be pairwise distinct.
+///
+/// Complexity: `O(d^2)`
+///
+/// ```
+/// use modint::{
+///     formal_power_series::FormalPowerSeries, interpolation::interpolate, ModInt998244353,
+/// };
+///
+/// let xs = [1, 2, 4].map(ModInt998244353::new);
+/// let ys = [3, 7, 21].map(ModInt998244353::new);
+/// let f = interpolate(&xs, &ys);
+/// assert_eq!(f, [1, 1, 1].map(ModInt998244353::new));
+/// let f = FormalPowerSeries(f);
+/// assert_eq!(f.evaluate(ModInt998244353::new(10)).get(), 111);
+/// ```
+pub fn interpolate<const M: u64>(
+    xs: &[StaticModInt<M>],
+    ys: &[StaticModInt<M>],
+) -> Vec<StaticModInt<M>> {
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+
+    // p(x) = (x - xs[0])(x - xs[1])...(x - xs[n - 1])
+    let mut p = vec![StaticModInt::zero(); n + 1];
+    p[0] = StaticModInt::one();
+    for (i, &x) in xs.iter().enumerate() {
+        for j in (0..=i).rev() {
+            let c = p[j];
+            p[j + 1] += c;
+            p[j] = -c * x;
+        }
+    }
+
+    let mut res = vec![StaticModInt::zero(); n];
+    for (&x, &y) in xs.iter().zip(ys) {
+        // q(x) = p(x) / (x - xs[i])
+        let mut q = vec![StaticModInt::zero(); n];
+        let mut carry = StaticModInt::zero();
+        for j in (0..n).rev() {
+            carry = p[j + 1] + carry * x;
+            q[j] = carry;
+        }
+
+        let q = FormalPowerSeries(q);
+        let denominator = q.evaluate(x);
+        assert!(!denominator.is_zero(), "xs must be pairwise distinct");
+        let coef = y * denominator.inv();
+        for (r, c) in res.iter_mut().zip(q.0) {
+            *r += coef * c;
+        }
+    }
+
+    res
+}
+
+#[test]
+fn interpolation_test() {
+    use crate::ModInt1000000007;
+    use num_traits::Pow;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for d in 0..30 {
+        let f = FormalPowerSeries(
+            (0..=d)
+                .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+                .collect(),
+        );
+        let values = (0..=d)
+            .map(|x| f.evaluate(ModInt1000000007::new(x)))
+            .collect::<Vec<_>>();
+        for n in [0, d as u64, d as u64 + 1, 1000000007, 1 << 60] {
+            assert_eq!(
+                lagrange_interpolation(&values, n),
+                f.evaluate(ModInt1000000007::new(n % 1000000007))
+            );
+        }
+
+        let xs = (0..=d)
+            .map(|i| ModInt1000000007::new(i * i + 3).pow(3u32))
+            .collect::<Vec<_>>();
+        let ys = xs.iter().map(|&x| f.evaluate(x)).collect::<Vec<_>>();
+        assert_eq!(interpolate(&xs, &ys), f.0);
+    }
+}
@@ -1,6 +1,8 @@
 use num_integer::Integer;
 use num_traits::{Inv, One, Pow, Zero};
+use prime::modular::pow_mod;
 use std::{
+    collections::HashMap,
     convert::TryInto,
     fmt::{Debug, Display, Formatter},
     iter::{Product, Sum},
@@ -9,7 +11,12 @@ use std::{
     str::FromStr,
 };
 
+pub mod convolution;
 pub mod factorial;
+pub mod formal_power_series;
+pub mod interpolation;
+pub mod lucas;
+pub mod recurrence;
 pub mod table;
 
 pub const MOD1000000007: u64 = 1000000007;
@@ -39,16 +46,145 @@ impl<const M: u64> StaticModInt<M> {
             )
         });
 
-        let value = if value > M as i64 {
+        let value = if value >= 0 {
             value as u64 % M
-        } else if value >= 0 {
-            value as u64
         } else {
-            M - (value.unsigned_abs() % M)
+            (M - value.unsigned_abs() % M) % M
         };
 
         Self(value)
     }
+
+    /// Rational reconstruction
+    ///
+    /// Returns `(p, q)` where `p / q == self`, `|p| ≤ sqrt(M / 2)` and `0 < q ≤ sqrt(M / 2)`, if exists.
+    /// Such a pair is unique, so this recovers small fractions like `1 / 3` for debugging.
+    ///
+    /// Complexity: `O(log M)`
+    ///
+    /// ```
+    /// use modint::ModInt998244353;
+    ///
+    /// let x = ModInt998244353::new(-2) / ModInt998244353::new(3);
+    /// assert_eq!(x.to_rational(), Some((-2, 3)));
+    /// assert_eq!(format!("{:#?}", x), "-2/3 (mod. 998244353)");
+    /// ```
+    pub fn to_rational(&self) -> Option<(i64, u64)> {
+        let bound = ((M / 2) as f64).sqrt() as i64;
+
+        // half-extended Euclidean algorithm, keeping `r ≡ t * self (mod. M)`
+        let (mut r0, mut r1) = (M as i64, self.get() as i64);
+        let (mut t0, mut t1) = (0i64, 1i64);
+        while r1 > bound {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+
+        let (p, q) = if t1 < 0 { (-r1, -t1) } else { (r1, t1) };
+        if q <= bound && p.gcd(&q) == 1 {
+            Some((p, q as u64))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest primitive root modulo `M`, evaluated at compile time
+    ///
+    /// `M` must be a prime. It factorizes `M - 1` by trial division in `O(√M)` steps,
+    /// so it is meant for NTT-friendly primes, not for huge moduli.
+    pub const PRIMITIVE_ROOT: u64 = smallest_primitive_root(M);
+
+    /// Returns the smallest primitive root modulo `M`, see [`Self::PRIMITIVE_ROOT`]
+    ///
+    /// `M` must be a prime.
+    pub fn primitive_root() -> Self {
+        Self::new(Self::PRIMITIVE_ROOT)
+    }
+
+    /// Tonelli–Shanks algorithm
+    ///
+    /// Returns `x` where `x * x == self`, if exists
+    ///
+    /// `M` must be a prime.
+    ///
+    /// See https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm
+    ///
+    /// Complexity: `O(log^2 M)`
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() || M == 2 {
+            return Some(*self);
+        }
+        if !self.pow((M - 1) / 2).is_one() {
+            return None;
+        }
+
+        let s = (M - 1).trailing_zeros();
+        let q = (M - 1) >> s;
+        let z = (2..)
+            .map(Self::new)
+            .find(|&z| !z.pow((M - 1) / 2).is_one())
+            .unwrap();
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+        while !t.is_one() {
+            let mut i = 0;
+            let mut t2 = t;
+            while !t2.is_one() {
+                t2 *= t2;
+                i += 1;
+            }
+            let b = c.pow(1u64 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        Some(r)
+    }
+
+    /// Baby-step giant-step algorithm
+    ///
+    /// Returns the smallest `x` where `base^x == self`, if exists
+    ///
+    /// `M` must be a prime.
+    ///
+    /// See https://en.wikipedia.org/wiki/Baby-step_giant-step
+    ///
+    /// Complexity: `O(sqrt M)`
+    pub fn log(&self, base: Self) -> Option<u64> {
+        if self.is_one() {
+            return Some(0);
+        }
+        if base.is_zero() || self.is_zero() {
+            return if base.is_zero() && self.is_zero() {
+                Some(1)
+            } else {
+                None
+            };
+        }
+
+        let m = (M as f64).sqrt().ceil() as u64;
+        let mut baby_steps = HashMap::new();
+        let mut cur = Self::one();
+        for j in 0..m {
+            baby_steps.entry(cur).or_insert(j);
+            cur *= base;
+        }
+
+        let giant_step = cur.inv();
+        let mut cur = *self;
+        for i in 0..m {
+            if let Some(&j) = baby_steps.get(&cur) {
+                return Some(i * m + j);
+            }
+            cur *= giant_step;
+        }
+        None
+    }
 }
 
 macro_rules! impl_from_primitive {
@@ -75,7 +211,16 @@ impl<const M: u64> FromStr for StaticModInt<M> {
 }
 
 impl<const M: u64> Debug for StaticModInt<M> {
+    /// Alternate format (`{:#?}`, used by `dbg!` and `trace!`)
+    /// shows the value as a small fraction if possible
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            match self.to_rational() {
+                Some((p, 1)) => return write!(f, "{} (mod. {})", p, M),
+                Some((p, q)) => return write!(f, "{}/{} (mod. {})", p, q, M),
+                None => {}
+            }
+        }
         write!(f, "{} (mod. {})", self.get(), M)
     }
 }
@@ -438,3 +583,123 @@ impl<const M: u64> Inv for StaticModInt<M> {
         }
     }
 }
+
+/// Returns the smallest primitive root modulo a prime `m` in constant context
+const fn smallest_primitive_root(m: u64) -> u64 {
+    if m == 2 {
+        return 1;
+    }
+
+    // `m - 1 < 2^64` has at most 15 distinct prime factors
+    let mut primes = [0; 15];
+    let mut count = 0;
+    let mut x = m - 1;
+    let mut p = 2;
+    while p * p <= x {
+        if x.is_multiple_of(p) {
+            primes[count] = p;
+            count += 1;
+            while x.is_multiple_of(p) {
+                x /= p;
+            }
+        }
+        p += 1;
+    }
+    if x > 1 {
+        primes[count] = x;
+        count += 1;
+    }
+
+    let mut g = 2;
+    loop {
+        let mut i = 0;
+        while i < count && pow_mod(g, (m - 1) / primes[i], m) != 1 {
+            i += 1;
+        }
+        if i == count {
+            return g;
+        }
+        g += 1;
+    }
+}
+
+#[test]
+fn primitive_root_test() {
+    assert_eq!(StaticModInt::<2>::primitive_root().get(), 1);
+    assert_eq!(StaticModInt::<167772161>::primitive_root().get(), 3);
+    assert_eq!(StaticModInt::<469762049>::primitive_root().get(), 3);
+    assert_eq!(StaticModInt::<754974721>::primitive_root().get(), 11);
+    assert_eq!(ModInt998244353::primitive_root().get(), 3);
+    assert_eq!(ModInt1000000007::primitive_root().get(), 5);
+
+    assert_eq!(StaticModInt::<2>::PRIMITIVE_ROOT, 1);
+    assert_eq!(StaticModInt::<754974721>::PRIMITIVE_ROOT, 11);
+    assert_eq!(ModInt998244353::PRIMITIVE_ROOT, 3);
+    assert_eq!(ModInt1000000007::PRIMITIVE_ROOT, 5);
+}
+
+#[test]
+fn sqrt_test() {
+    fn check<const M: u64>() {
+        let squares = (0..M)
+            .map(|x| StaticModInt::<M>::new(x * x))
+            .collect::<std::collections::HashSet<_>>();
+        for a in (0..M).map(StaticModInt::<M>::new) {
+            match a.sqrt() {
+                Some(x) => assert_eq!(x * x, a),
+                None => assert!(!squares.contains(&a)),
+            }
+        }
+    }
+
+    check::<2>();
+    check::<3>();
+    check::<13>();
+    check::<17>();
+    check::<97>();
+
+    for x in [0u64, 1, 2, 12345, 998244352] {
+        let a = ModInt998244353::new(x) * x;
+        let r = a.sqrt().unwrap();
+        assert_eq!(r * r, a);
+    }
+}
+
+#[test]
+fn log_test() {
+    const M: u64 = 97;
+    for base in 0..M {
+        let base = StaticModInt::<M>::new(base);
+        for a in 0..M {
+            let a = StaticModInt::<M>::new(a);
+            let expected = (0..M).find(|&x| base.pow(x) == a);
+            assert_eq!(a.log(base), expected);
+        }
+    }
+
+    let g = ModInt1000000007::new(5);
+    assert_eq!(g.pow(123456789u64).log(g), Some(123456789));
+}
+
+#[test]
+fn to_rational_test() {
+    for q in 1..100i64 {
+        for p in -100..100i64 {
+            if p.gcd(&q) == 1 {
+                let x = ModInt998244353::new(p) / ModInt998244353::new(q);
+                assert_eq!(x.to_rational(), Some((p, q as u64)));
+            }
+        }
+    }
+
+    assert_eq!(ModInt998244353::new(0).to_rational(), Some((0, 1)));
+    assert_eq!(ModInt998244353::new(123456789).to_rational(), None);
+    assert_eq!(
+        format!("{:?}", ModInt998244353::new(1) / 3),
+        "332748118 (mod. 998244353)"
+    );
+    assert_eq!(
+        format!("{:#?}", ModInt998244353::new(123456789)),
+        "123456789 (mod. 998244353)"
+    );
+}
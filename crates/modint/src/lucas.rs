@@ -0,0 +1,266 @@
+use crate::{table::CombTable, StaticModInt};
+use num_traits::{One, Zero};
+use prime::{
+    factorize::Factorization,
+    modular::{inv_mod, pow_mod},
+};
+
+/// # Lucas's theorem
+///
+/// Calculates `C(n, k) mod M` for huge `n`, where `M` is a small prime
+///
+/// See https://en.wikipedia.org/wiki/Lucas%27s_theorem
+///
+/// Complexity: `O(M)` to build, `O(log_M n)` for each query
+///
+/// ```
+/// use modint::lucas::LucasTable;
+///
+/// let table = LucasTable::<13>::new();
+/// assert_eq!(table.combination(13u64.pow(15) + 5, 2).get(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LucasTable<const M: u64> {
+    table: CombTable<M>,
+}
+
+impl<const M: u64> LucasTable<M> {
+    pub fn new() -> Self {
+        assert!(M >= 2, "modulus must be a prime");
+        Self {
+            table: CombTable::new(M as usize - 1),
+        }
+    }
+
+    pub fn combination(&self, mut n: u64, mut k: u64) -> StaticModInt<M> {
+        if k > n {
+            return StaticModInt::zero();
+        }
+
+        let mut res = StaticModInt::one();
+        while k > 0 && !res.is_zero() {
+            res *= self.table.combination((n % M) as usize, (k % M) as usize);
+            n /= M;
+            k /= M;
+        }
+        res
+    }
+
+    pub fn binomial(&self, n: u64, k: u64) -> StaticModInt<M> {
+        self.combination(n, k)
+    }
+}
+
+impl<const M: u64> Default for LucasTable<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The largest prime power supported by [`PrimePowerBinomial`] and [`ArbitraryModBinomial`]
+pub const MAX_PRIME_POWER: u64 = 10_000_000;
+
+/// Calculates `C(n, k) mod p^e` for huge `n`, by generalized Lucas's theorem
+///
+/// See Andrew Granville, "Binomial coefficients modulo prime powers"
+///
+/// `p^e` must be at most [`MAX_PRIME_POWER`], since a table of size `p^e` is built.
+///
+/// Complexity: `O(p^e)` to build, `O(log_p n)` for each query
+#[derive(Debug, Clone)]
+pub struct PrimePowerBinomial {
+    p: u64,
+    e: u32,
+    q: u64,
+    /// products of `1..=i` excluding multiples of `p`, modulo `q`
+    unit_factorials: Box<[u64]>,
+}
+
+impl PrimePowerBinomial {
+    pub fn new(p: u64, e: u32) -> Self {
+        assert!(
+            p.checked_pow(e).is_some_and(|q| q <= MAX_PRIME_POWER),
+            "{}^{} exceeds the table size limit {}",
+            p,
+            e,
+            MAX_PRIME_POWER
+        );
+        let q = p.pow(e);
+
+        let mut unit_factorials = vec![1u64; q as usize];
+        for i in 1..q as usize {
+            unit_factorials[i] = if (i as u64).is_multiple_of(p) {
+                unit_factorials[i - 1]
+            } else {
+                unit_factorials[i - 1] * i as u64 % q
+            };
+        }
+
+        Self {
+            p,
+            e,
+            q,
+            unit_factorials: unit_factorials.into_boxed_slice(),
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.q
+    }
+
+    /// Returns `n! / p^(v_p(n!)) mod q`
+    fn unit_factorial(&self, mut n: u64) -> u64 {
+        let full = self.unit_factorials[self.q as usize - 1];
+        let mut res = 1;
+        while n > 0 {
+            res = res * pow_mod(full, n / self.q, self.q) % self.q;
+            res = res * self.unit_factorials[(n % self.q) as usize] % self.q;
+            n /= self.p;
+        }
+        res
+    }
+
+    /// Returns `v_p(n!)` by Legendre's formula
+    fn factorial_valuation(&self, mut n: u64) -> u64 {
+        let mut res = 0;
+        while n > 0 {
+            n /= self.p;
+            res += n;
+        }
+        res
+    }
+
+    pub fn combination(&self, n: u64, k: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        let v = self.factorial_valuation(n)
+            - self.factorial_valuation(k)
+            - self.factorial_valuation(n - k);
+        if v >= self.e as u64 {
+            return 0;
+        }
+
+        let denominator = self.unit_factorial(k) * self.unit_factorial(n - k) % self.q;
+        let denominator_inv = inv_mod(denominator as i64, self.q as i64).unwrap() as u64;
+        self.unit_factorial(n) * denominator_inv % self.q * self.p.pow(v as u32) % self.q
+    }
+
+    pub fn binomial(&self, n: u64, k: u64) -> u64 {
+        self.combination(n, k)
+    }
+}
+
+/// Calculates `C(n, k) mod m` for huge `n` and arbitrary `m`,
+/// combining [`PrimePowerBinomial`] for each prime power factor of `m` by CRT
+///
+/// Each prime power factor of `m` must be at most [`MAX_PRIME_POWER`],
+/// so a large prime modulus like 1000000007 is not supported.
+///
+/// Complexity: `O(Σ p^e)` to build, `O(Σ log_p n)` for each query
+///
+/// ```
+/// use modint::lucas::ArbitraryModBinomial;
+///
+/// let table = ArbitraryModBinomial::new(100);
+/// assert_eq!(table.combination(10, 3), 20);
+/// assert_eq!(table.combination(999_999_999_999_999_999, 3), 99);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArbitraryModBinomial {
+    modulus: u64,
+    factors: Vec<PrimePowerBinomial>,
+}
+
+impl ArbitraryModBinomial {
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0);
+        let factors = match modulus.factorize() {
+            Ok(factors) => factors
+                .into_iter()
+                .map(|(p, e)| PrimePowerBinomial::new(p, e as u32))
+                .collect(),
+            Err(_) => vec![],
+        };
+
+        Self { modulus, factors }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn combination(&self, n: u64, k: u64) -> u64 {
+        // Garner's algorithm
+        let mut res = 0;
+        let mut product = 1;
+        for f in self.factors.iter() {
+            let r = f.combination(n, k);
+            let t = (r + f.q - res % f.q) % f.q
+                * inv_mod((product % f.q) as i64, f.q as i64).unwrap() as u64
+                % f.q;
+            res += product * t;
+            product *= f.q;
+        }
+        res % self.modulus
+    }
+
+    pub fn binomial(&self, n: u64, k: u64) -> u64 {
+        self.combination(n, k)
+    }
+}
+
+#[cfg(test)]
+fn pascal(n: usize, m: u64) -> Vec<Vec<u64>> {
+    let mut c = vec![vec![0; n + 1]; n + 1];
+    for i in 0..=n {
+        c[i][0] = 1 % m;
+        for j in 1..=i {
+            c[i][j] = (c[i - 1][j - 1] + c[i - 1][j]) % m;
+        }
+    }
+    c
+}
+
+#[test]
+fn lucas_test() {
+    fn check<const M: u64>() {
+        let table = LucasTable::<M>::new();
+        let c = pascal(200, M);
+        for n in 0..=200 {
+            for k in 0..=200 {
+                assert_eq!(table.combination(n, k).get(), c[n as usize][k as usize]);
+            }
+        }
+    }
+
+    check::<2>();
+    check::<3>();
+    check::<13>();
+    check::<101>();
+
+    let table = LucasTable::<2>::new();
+    for (n, k) in [(u64::MAX, 12345), (1 << 60, 1 << 59), (1 << 60, 1 << 60)] {
+        assert_eq!(table.combination(n, k).get(), (n & k == k) as u64);
+    }
+}
+
+#[test]
+fn arbitrary_mod_binomial_test() {
+    for m in 1..=100 {
+        let table = ArbitraryModBinomial::new(m);
+        let c = pascal(100, m);
+        for n in 0..=100 {
+            for k in 0..=100 {
+                assert_eq!(table.combination(n, k), c[n as usize][k as usize]);
+            }
+        }
+    }
+
+    let table = ArbitraryModBinomial::new(2 * 9_765_625);
+    assert_eq!(table.combination(10, 3), 120);
+    assert!(std::panic::catch_unwind(|| ArbitraryModBinomial::new(1_000_000_007)).is_err());
+    assert!(std::panic::catch_unwind(|| PrimePowerBinomial::new(2, 64)).is_err());
+    assert!(std::panic::catch_unwind(LucasTable::<1>::new).is_err());
+}
@@ -0,0 +1,237 @@
+use crate::{convolution::convolution_mod, StaticModInt};
+use num_traits::{One, Zero};
+
+/// # Berlekamp–Massey algorithm
+///
+/// Returns the shortest `c` where `a[i] = Σ c[j] * a[i - j - 1]` holds for all `i ≥ |c|`
+///
+/// `M` must be a prime.
+/// To find a recurrence of order `d`, at least `2d` terms are needed.
+///
+/// See https://en.wikipedia.org/wiki/Berlekamp%E2%80%93Massey_algorithm
+///
+/// Complexity: `O(n^2)`
+///
+/// ```
+/// use modint::{recurrence::berlekamp_massey, ModInt998244353};
+///
+/// let fib = [0, 1, 1, 2, 3, 5, 8, 13].map(ModInt998244353::new);
+/// assert_eq!(berlekamp_massey(&fib), vec![ModInt998244353::new(1); 2]);
+/// ```
+pub fn berlekamp_massey<const M: u64>(a: &[StaticModInt<M>]) -> Vec<StaticModInt<M>> {
+    let n = a.len();
+    let mut c = vec![StaticModInt::zero(); n + 1];
+    let mut b = vec![StaticModInt::zero(); n + 1];
+    c[0] = StaticModInt::one();
+    b[0] = StaticModInt::one();
+
+    let mut len = 0;
+    let mut shift = 0;
+    let mut last = StaticModInt::one();
+    for i in 0..n {
+        shift += 1;
+        let d = (1..=len).fold(a[i], |acc, j| acc + c[j] * a[i - j]);
+        if d.is_zero() {
+            continue;
+        }
+
+        let prev = c.clone();
+        let coef = d / last;
+        for j in shift..=n {
+            c[j] -= coef * b[j - shift];
+        }
+        if 2 * len > i {
+            continue;
+        }
+
+        len = i + 1 - len;
+        b = prev;
+        last = d;
+        shift = 0;
+    }
+
+    c[1..=len].iter().map(|&x| -x).collect()
+}
+
+/// # Bostan–Mori algorithm
+///
+/// Returns `[x^n] p(x) / q(x)`
+///
+/// `q[0]` must not be zero.
+///
+/// See https://arxiv.org/abs/2008.08822
+///
+/// Complexity: `O(d log d log n)` where `d = max(|p|, |q|)`
+pub fn bostan_mori<const M: u64>(
+    p: &[StaticModInt<M>],
+    q: &[StaticModInt<M>],
+    mut n: u64,
+) -> StaticModInt<M> {
+    assert!(
+        q.first().is_some_and(|x| !x.is_zero()),
+        "constant term of denominator must not be zero"
+    );
+
+    let mut p = p.to_vec();
+    let mut q = q.to_vec();
+    while n > 0 {
+        let q_neg = q
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| if i % 2 == 0 { x } else { -x })
+            .collect::<Vec<_>>();
+        let u = convolution_mod(&p, &q_neg);
+        let v = convolution_mod(&q, &q_neg);
+        p = u.into_iter().skip(n as usize % 2).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+
+    p.first().copied().unwrap_or_else(StaticModInt::zero) / q[0]
+}
+
+/// Returns the `n`-th term of linear recurrence `a[i] = Σ c[j] * a[i - j - 1]`
+///
+/// `a` must contain at least `|c|` initial terms.
+///
+/// Complexity: `O(d log d log n)` where `d = |c|`
+///
+/// ```
+/// use modint::{recurrence::linear_recurrence, ModInt998244353};
+///
+/// let a = [0, 1].map(ModInt998244353::new);
+/// let c = [1, 1].map(ModInt998244353::new);
+/// assert_eq!(linear_recurrence(&a, &c, 90).get(), 2880067194370816120 % 998244353);
+/// ```
+pub fn linear_recurrence<const M: u64>(
+    a: &[StaticModInt<M>],
+    c: &[StaticModInt<M>],
+    n: u64,
+) -> StaticModInt<M> {
+    assert!(a.len() >= c.len(), "not enough initial terms");
+    if n < a.len() as u64 {
+        return a[n as usize];
+    }
+
+    let d = c.len();
+    let mut q = vec![StaticModInt::one()];
+    q.extend(c.iter().map(|&x| -x));
+    let mut p = convolution_mod(&a[..d], &q);
+    p.truncate(d);
+
+    bostan_mori(&p, &q, n)
+}
+
+/// Returns the `n`-th term of a sequence which satisfies some linear recurrence,
+/// inferring the recurrence from the prefix `a` by [`berlekamp_massey`]
+///
+/// `M` must be a prime.
+///
+/// ```
+/// use modint::{recurrence::nth_term, ModInt998244353};
+///
+/// // sum of squares: 0, 1, 5, 14, 30, ...
+/// let a = (0..10u64)
+///     .scan(0, |acc, x| {
+///         *acc += x * x;
+///         Some(ModInt998244353::new(*acc))
+///     })
+///     .collect::<Vec<_>>();
+/// assert_eq!(nth_term(&a, 100).get(), 338350);
+/// ```
+pub fn nth_term<const M: u64>(a: &[StaticModInt<M>], n: u64) -> StaticModInt<M> {
+    let c = berlekamp_massey(a);
+    linear_recurrence(a, &c, n)
+}
+
+#[test]
+fn recurrence_test() {
+    use crate::ModInt1000000007;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for d in [1, 2, 3, 10, 70] {
+        let c = (0..d)
+            .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+            .collect::<Vec<_>>();
+        let mut a = (0..d)
+            .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+            .collect::<Vec<_>>();
+        for i in d..200 {
+            let x = (0..d).map(|j| c[j] * a[i - j - 1]).sum();
+            a.push(x);
+        }
+
+        let found = berlekamp_massey(&a[..2 * d]);
+        assert!(found.len() <= d);
+        for _ in 0..10 {
+            let n = rng.gen_range(0..a.len());
+            let x = a[n];
+            assert_eq!(linear_recurrence(&a[..d], &c, n as u64), x);
+            assert_eq!(linear_recurrence(&a[..2 * d], &found, n as u64), x);
+        }
+    }
+
+    // F(2k) = F(k) (2 F(k + 1) - F(k)), F(2k + 1) = F(k)^2 + F(k + 1)^2
+    fn fibonacci(n: u64) -> (ModInt1000000007, ModInt1000000007) {
+        if n == 0 {
+            return (ModInt1000000007::zero(), ModInt1000000007::one());
+        }
+        let (f0, f1) = fibonacci(n / 2);
+        let (g0, g1) = (f0 * (f1 * 2 - f0), f0 * f0 + f1 * f1);
+        if n.is_multiple_of(2) {
+            (g0, g1)
+        } else {
+            (g1, g0 + g1)
+        }
+    }
+    let fib = [0, 1, 1, 2].map(ModInt1000000007::new);
+    let one = [ModInt1000000007::one(); 2];
+    for n in [10u64.pow(18), 10u64.pow(18) + 1, u64::MAX] {
+        let x = fibonacci(n).0;
+        assert_eq!(linear_recurrence(&fib[..2], &one, n), x);
+        assert_eq!(nth_term(&fib, n), x);
+    }
+
+    // x^n mod the characteristic polynomial by binary exponentiation
+    let c = (0..70)
+        .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+        .collect::<Vec<_>>();
+    let a = (0..70)
+        .map(|_| ModInt1000000007::new(rng.gen_range(0..1000000007)))
+        .collect::<Vec<_>>();
+    let mul = |f: &[ModInt1000000007], g: &[ModInt1000000007]| {
+        let mut h = vec![ModInt1000000007::zero(); 2 * c.len()];
+        for (i, &x) in f.iter().enumerate() {
+            for (j, &y) in g.iter().enumerate() {
+                h[i + j] += x * y;
+            }
+        }
+        for i in (c.len()..h.len()).rev() {
+            let x = h[i];
+            for (j, &y) in c.iter().enumerate() {
+                h[i - j - 1] += x * y;
+            }
+        }
+        h.truncate(c.len());
+        h
+    };
+    let n = 10u64.pow(18);
+    let mut r = vec![ModInt1000000007::zero(); c.len()];
+    r[0] = ModInt1000000007::one();
+    let mut x = vec![ModInt1000000007::zero(); c.len()];
+    x[1] = ModInt1000000007::one();
+    for i in 0..u64::BITS - n.leading_zeros() {
+        if n >> i & 1 == 1 {
+            r = mul(&r, &x);
+        }
+        x = mul(&x, &x);
+    }
+    let expected = r.iter().zip(&a).map(|(&r, &a)| r * a).sum();
+    assert_eq!(linear_recurrence(&a, &c, n), expected);
+
+    assert!(berlekamp_massey::<1000000007>(&[]).is_empty());
+    let zeros = [StaticModInt::<1000000007>::zero(); 5];
+    assert!(berlekamp_massey(&zeros).is_empty());
+    assert!(nth_term(&zeros, 1 << 60).is_zero());
+}
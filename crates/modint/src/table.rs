@@ -1,5 +1,5 @@
 use crate::StaticModInt;
-use num_traits::{Inv, One};
+use num_traits::{Inv, One, Zero};
 
 #[derive(Debug, Clone)]
 pub struct CombTable<const M: u64> {
@@ -36,15 +36,239 @@ impl<const M: u64> CombTable<M> {
         self.factorials_inversed[k]
     }
 
+    /// Returns `n! / (n - k)!`, which is zero if `k > n`
     pub fn permutation(&self, n: usize, k: usize) -> StaticModInt<M> {
-        self.factorial(n) * self.factorial_inversed(n - k)
+        if k > n {
+            StaticModInt::zero()
+        } else {
+            self.factorial(n) * self.factorial_inversed(n - k)
+        }
     }
 
+    /// Returns `n! / k! (n - k)!`, which is zero if `k > n`
     pub fn combination(&self, n: usize, k: usize) -> StaticModInt<M> {
-        self.permutation(n, k) * self.factorial_inversed(k)
+        if k > n {
+            StaticModInt::zero()
+        } else {
+            self.factorial(n) * self.factorial_inversed(n - k) * self.factorial_inversed(k)
+        }
     }
 
     pub fn binomial(&self, n: usize, k: usize) -> StaticModInt<M> {
         self.combination(n, k)
     }
+
+    /// Returns the number of ways to choose `k` items from `n` kinds with repetition,
+    /// that is, `C(n + k - 1, k)`
+    ///
+    /// Table must be built with at least `n + k - 1`.
+    pub fn multichoose(&self, n: usize, k: usize) -> StaticModInt<M> {
+        if n == 0 {
+            if k == 0 {
+                StaticModInt::one()
+            } else {
+                StaticModInt::zero()
+            }
+        } else {
+            assert!(
+                n + k - 1 < self.factorials.len(),
+                "table is too small for multichoose({n}, {k})"
+            );
+            self.combination(n + k - 1, k)
+        }
+    }
+
+    /// Returns `(k1 + k2 + ...)! / k1! k2! ...`
+    pub fn multinomial(&self, ks: &[usize]) -> StaticModInt<M> {
+        ks.iter().fold(self.factorial(ks.iter().sum()), |acc, &k| {
+            acc * self.factorial_inversed(k)
+        })
+    }
+
+    /// Returns the `n`-th Catalan number, `C(2n, n) / (n + 1)`
+    ///
+    /// Table must be built with at least `2n`.
+    pub fn catalan(&self, n: usize) -> StaticModInt<M> {
+        self.factorial(2 * n) * self.factorial_inversed(n + 1) * self.factorial_inversed(n)
+    }
+}
+
+/// Table of Stirling numbers of both kinds
+///
+/// Complexity: `O(n^2)` to build
+#[derive(Debug, Clone)]
+pub struct StirlingTable<const M: u64> {
+    first: Vec<Vec<StaticModInt<M>>>,
+    second: Vec<Vec<StaticModInt<M>>>,
+}
+
+impl<const M: u64> StirlingTable<M> {
+    pub fn new(n: usize) -> Self {
+        let mut first = vec![vec![StaticModInt::one()]];
+        let mut second = vec![vec![StaticModInt::one()]];
+        for i in 1..=n {
+            let mut f = vec![StaticModInt::zero(); i + 1];
+            let mut s = vec![StaticModInt::zero(); i + 1];
+            for k in 1..=i {
+                f[k] = first[i - 1][k - 1];
+                s[k] = second[i - 1][k - 1];
+                if k < i {
+                    f[k] += first[i - 1][k] * (i - 1);
+                    s[k] += second[i - 1][k] * k;
+                }
+            }
+            first.push(f);
+            second.push(s);
+        }
+
+        Self { first, second }
+    }
+
+    /// Returns the unsigned Stirling number of the first kind,
+    /// the number of permutations of `n` items with `k` cycles
+    pub fn first(&self, n: usize, k: usize) -> StaticModInt<M> {
+        self.first[n]
+            .get(k)
+            .copied()
+            .unwrap_or_else(StaticModInt::zero)
+    }
+
+    /// Returns the Stirling number of the second kind,
+    /// the number of ways to partition `n` items into `k` non-empty sets
+    pub fn second(&self, n: usize, k: usize) -> StaticModInt<M> {
+        self.second[n]
+            .get(k)
+            .copied()
+            .unwrap_or_else(StaticModInt::zero)
+    }
+}
+
+/// Table of Bell numbers, the number of partitions of a set
+///
+/// Complexity: `O(n^2)` to build
+#[derive(Debug, Clone)]
+pub struct BellTable<const M: u64> {
+    bell: Box<[StaticModInt<M>]>,
+}
+
+impl<const M: u64> BellTable<M> {
+    pub fn new(n: usize) -> Self {
+        // Bell triangle: each row starts with the last item of the previous row
+        let mut bell = vec![StaticModInt::one()];
+        let mut row = vec![StaticModInt::one()];
+        for _ in 0..n {
+            let mut next = vec![*row.last().unwrap()];
+            for &x in row.iter() {
+                next.push(*next.last().unwrap() + x);
+            }
+            bell.push(next[0]);
+            row = next;
+        }
+
+        Self {
+            bell: bell.into_boxed_slice(),
+        }
+    }
+
+    pub fn bell(&self, n: usize) -> StaticModInt<M> {
+        self.bell[n]
+    }
+}
+
+/// Table of partition numbers, the number of ways to write `n` as a sum of positive integers
+///
+/// Complexity: `O(n sqrt n)` to build by the pentagonal number theorem
+#[derive(Debug, Clone)]
+pub struct PartitionTable<const M: u64> {
+    partitions: Box<[StaticModInt<M>]>,
+}
+
+impl<const M: u64> PartitionTable<M> {
+    pub fn new(n: usize) -> Self {
+        let mut partitions = vec![StaticModInt::zero(); n + 1];
+        partitions[0] = StaticModInt::one();
+        for i in 1..=n {
+            for k in 1.. {
+                let pentagonal = k * (3 * k - 1) / 2;
+                if pentagonal > i {
+                    break;
+                }
+                let mut x = partitions[i - pentagonal];
+                if pentagonal + k <= i {
+                    x += partitions[i - pentagonal - k];
+                }
+                if k % 2 == 1 {
+                    partitions[i] += x;
+                } else {
+                    partitions[i] -= x;
+                }
+            }
+        }
+
+        Self {
+            partitions: partitions.into_boxed_slice(),
+        }
+    }
+
+    pub fn partition(&self, n: usize) -> StaticModInt<M> {
+        self.partitions[n]
+    }
+}
+
+#[test]
+fn comb_table_test() {
+    let table = CombTable::<998244353>::new(20);
+    assert_eq!(table.combination(5, 2).get(), 10);
+    assert_eq!(table.combination(2, 5).get(), 0);
+    assert_eq!(table.permutation(2, 5).get(), 0);
+    // `k` exceeding the table size
+    assert_eq!(table.combination(2, 25).get(), 0);
+    assert_eq!(table.permutation(2, 25).get(), 0);
+    assert_eq!(table.multichoose(0, 25).get(), 0);
+    assert_eq!(table.multichoose(3, 2).get(), 6);
+    assert_eq!(table.multichoose(0, 0).get(), 1);
+    assert_eq!(table.multichoose(0, 2).get(), 0);
+    assert_eq!(table.multinomial(&[2, 1, 1]).get(), 12);
+    assert_eq!(
+        (0..=10).map(|n| table.catalan(n).get()).collect::<Vec<_>>(),
+        vec![1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796]
+    );
+}
+
+#[test]
+fn companion_tables_test() {
+    let stirling = StirlingTable::<998244353>::new(10);
+    assert_eq!(
+        (0..=4)
+            .map(|k| stirling.first(4, k).get())
+            .collect::<Vec<_>>(),
+        vec![0, 6, 11, 6, 1]
+    );
+    assert_eq!(
+        (0..=5)
+            .map(|k| stirling.second(5, k).get())
+            .collect::<Vec<_>>(),
+        vec![0, 1, 15, 25, 10, 1]
+    );
+    assert_eq!(stirling.first(0, 0).get(), 1);
+    assert_eq!(stirling.second(3, 5).get(), 0);
+
+    let bell = BellTable::<998244353>::new(10);
+    assert_eq!(
+        (0..=10).map(|n| bell.bell(n).get()).collect::<Vec<_>>(),
+        vec![1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147, 115975]
+    );
+    for n in 0..=10 {
+        let sum: StaticModInt<998244353> = (0..=n).map(|k| stirling.second(n, k)).sum();
+        assert_eq!(bell.bell(n), sum);
+    }
+
+    let partition = PartitionTable::<998244353>::new(100);
+    assert_eq!(
+        (0..=10)
+            .map(|n| partition.partition(n).get())
+            .collect::<Vec<_>>(),
+        vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42]
+    );
+    assert_eq!(partition.partition(100).get(), 190569292);
 }
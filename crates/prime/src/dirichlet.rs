@@ -0,0 +1,314 @@
+use crate::quotient::quotient_blocks;
+use crate::sieve::eratosthenes::SieveOfEratosthenes;
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul, Sub};
+
+/// # Dirichlet convolution
+///
+/// See https://en.wikipedia.org/wiki/Dirichlet_convolution
+///
+/// Returns `h` where `h[n] = Σ_{d | n} f[d] g[n / d]` for `1 ≤ n < |f|`.
+/// Index `0` is ignored and set to zero.
+///
+/// Complexity: `O(n log n)`
+///
+/// ```
+/// use prime::dirichlet::dirichlet_convolution;
+///
+/// // 1 * 1 = d, the number of divisors
+/// let one = vec![1u64; 13];
+/// assert_eq!(
+///     dirichlet_convolution(&one, &one),
+///     vec![0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]
+/// );
+/// ```
+pub fn dirichlet_convolution<T>(f: &[T], g: &[T]) -> Vec<T>
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    assert_eq!(f.len(), g.len());
+    let n = f.len();
+    let mut h = vec![T::zero(); n];
+    for i in 1..n {
+        for j in 1..=(n - 1) / i {
+            h[i * j] = h[i * j] + f[i] * g[j];
+        }
+    }
+    h
+}
+
+/// # Dirichlet hyperbola method
+///
+/// See https://en.wikipedia.org/wiki/Dirichlet_hyperbola_method
+///
+/// Returns `Σ_{k=1}^{n} (f * g)(k)`,
+/// given `f`, `g` and their prefix sums `F`, `G` at `⌊n / k⌋`.
+///
+/// Complexity: `O(√n)` evaluations
+///
+/// ```
+/// use prime::dirichlet::hyperbola;
+///
+/// // Σ d(k) = Σ ⌊n / k⌋
+/// assert_eq!(hyperbola(1000000u64, |_| 1, |_| 1, |x| x, |x| x), 13970034);
+/// ```
+pub fn hyperbola<T, F, G, SF, SG>(n: u64, f: F, g: G, sum_f: SF, sum_g: SG) -> T
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    F: Fn(u64) -> T,
+    G: Fn(u64) -> T,
+    SF: Fn(u64) -> T,
+    SG: Fn(u64) -> T,
+{
+    let s = n.isqrt();
+    let mut res = T::zero();
+    for d in 1..=s {
+        res = res + f(d) * sum_g(n / d) + g(d) * sum_f(n / d);
+    }
+    res - sum_f(s) * sum_g(s)
+}
+
+/// # Du's sieve
+///
+/// Computes the prefix sums `S(x) = Σ_{k=1}^{x} f(k)` for every `x` of the form `⌊n / k⌋`,
+/// using a function `g` with `g(1) = 1` such that the prefix sums `G` of `g`
+/// and `H` of `h = f * g` are easy to compute.
+/// It is based on `S(x) = H(x) - Σ_{i=2}^{x} g(i) S(⌊x / i⌋)`.
+///
+/// `small[x]` must be `S(x)` for `x < |small|`, which must be greater than `√n`.
+/// Taking `|small| ≈ n^2/3` from a linear sieve is optimal.
+///
+/// Complexity: `O(n / √|small|)`
+///
+/// ```
+/// use prime::dirichlet::DuSieve;
+/// use prime::sieve::linear::LinearSieve;
+///
+/// // φ * 1 = id
+/// let n = 1_000_000_000u64;
+/// let sieve = LinearSieve::new(1_000_000);
+/// let small = (0..=1_000_000)
+///     .scan(0, |acc, i| {
+///         *acc += if i == 0 { 0 } else { sieve.phi(i) as u128 };
+///         Some(*acc)
+///     })
+///     .collect::<Vec<_>>();
+/// let phi = DuSieve::new(n, small, |x| x as u128, |x| x as u128 * (x as u128 + 1) / 2);
+/// assert_eq!(phi.get(n), 303963551173008414);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DuSieve<T> {
+    n: u64,
+    small: Vec<T>,
+    /// `large[k] = S(⌊n / k⌋)` for `⌊n / k⌋ ≥ |small|`
+    large: Vec<T>,
+}
+
+impl<T> DuSieve<T>
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn new<G, H>(n: u64, small: Vec<T>, sum_g: G, sum_h: H) -> Self
+    where
+        G: Fn(u64) -> T,
+        H: Fn(u64) -> T,
+    {
+        assert!(small.len() as u64 > n.isqrt());
+        let limit = small.len() as u64;
+        let k_max = if n < limit { 0 } else { n / limit };
+
+        let mut res = Self {
+            n,
+            small,
+            large: vec![T::zero(); k_max as usize + 1],
+        };
+        // Ascending order of x, so that S(⌊x / i⌋) is already known
+        for k in (1..=k_max).rev() {
+            let x = n / k;
+            let mut s = sum_h(x);
+            for (q, range) in quotient_blocks(x).skip(1) {
+                let g = sum_g(range.end - 1) - sum_g(range.start - 1);
+                s = s - g * res.get_inner(q, k * range.start);
+            }
+            res.large[k as usize] = s;
+        }
+        res
+    }
+
+    /// Returns `S(x)`, where `x = ⌊n / k⌋`
+    fn get_inner(&self, x: u64, k: u64) -> T {
+        if x < self.small.len() as u64 {
+            self.small[x as usize]
+        } else {
+            self.large[k as usize]
+        }
+    }
+
+    /// Returns `Σ_{k=1}^{x} f(k)`, where `x` must be `⌊n / k⌋` for some `k`
+    pub fn get(&self, x: u64) -> T {
+        if x < self.small.len() as u64 {
+            self.small[x as usize]
+        } else {
+            let k = self.n / x;
+            debug_assert_eq!(self.n / k, x);
+            self.large[k as usize]
+        }
+    }
+}
+
+fn min_25_rec<T, P, F>(x: u64, j: usize, primes: &[usize], prime_sum: &P, f: &F) -> T
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    P: Fn(u64) -> T,
+    F: Fn(u64, u32) -> T,
+{
+    let prev = if j == 0 { 0 } else { primes[j - 1] as u64 };
+    if x <= prev {
+        return T::zero();
+    }
+
+    let mut res = prime_sum(x) - prime_sum(prev);
+    for (k, &p) in primes.iter().enumerate().skip(j) {
+        let p = p as u64;
+        if p * p > x {
+            break;
+        }
+
+        let mut pe = p;
+        let mut e = 1;
+        while pe * p <= x {
+            res = res + f(p, e) * min_25_rec(x / pe, k + 1, primes, prime_sum, f) + f(p, e + 1);
+            pe *= p;
+            e += 1;
+        }
+    }
+    res
+}
+
+/// # Min_25 sieve
+///
+/// Returns `Σ_{k=1}^{n} f(k)` for a multiplicative function `f`,
+/// given `Σ f(p)` over the primes `p ≤ x` for each `x` of the form `⌊n / k⌋`,
+/// and `f(p^e)` as `f(p, e)`.
+/// The prime sums are usually built from [`PrimeSum`](crate::prime_count::PrimeSum)
+/// when `f(p)` is a polynomial in `p`.
+///
+/// Complexity: `O(n^3/4 / log n)`
+///
+/// ```
+/// use prime::dirichlet::min_25;
+/// use prime::prime_count::PrimeSum;
+///
+/// // φ(p) = p - 1
+/// let n = 1_000_000_000u64;
+/// let count = PrimeSum::new(n, |_| 1u128, |x| x as u128);
+/// let sum = PrimeSum::new(n, |p| p as u128, |x| x as u128 * (x as u128 + 1) / 2);
+/// let phi = min_25(
+///     n,
+///     |x| sum.get(x) - count.get(x),
+///     |p, e| (p as u128 - 1) * (p as u128).pow(e - 1),
+/// );
+/// assert_eq!(phi, 303963551173008414);
+/// ```
+pub fn min_25<T, P, F>(n: u64, prime_sum: P, f: F) -> T
+where
+    T: Copy + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    P: Fn(u64) -> T,
+    F: Fn(u64, u32) -> T,
+{
+    if n == 0 {
+        return T::zero();
+    }
+    let sieve = SieveOfEratosthenes::new(n.isqrt().max(1) as usize);
+    min_25_rec(n, 0, sieve.primes(), &prime_sum, &f) + T::one()
+}
+
+#[test]
+fn dirichlet_test() {
+    use crate::prime_count::PrimeSum;
+    use crate::sieve::linear::LinearSieve;
+
+    const N: usize = 100000;
+    let sieve = LinearSieve::new(N);
+    let prefix = |f: &dyn Fn(usize) -> i64| {
+        (0..=N)
+            .scan(0, |acc, i| {
+                *acc += if i == 0 { 0 } else { f(i) };
+                Some(*acc)
+            })
+            .collect::<Vec<_>>()
+    };
+    let phi = prefix(&|i| sieve.phi(i) as i64);
+    let mu = prefix(&|i| sieve.mu(i) as i64);
+    let d = prefix(&|i| sieve.divisor_count(i) as i64);
+
+    // Dirichlet convolution
+    let one = vec![1i64; N + 1];
+    let id = (0..=N as i64).collect::<Vec<_>>();
+    let phi_table = (0..=N)
+        .map(|i| if i == 0 { 0 } else { sieve.phi(i) as i64 })
+        .collect::<Vec<_>>();
+    let mu_table = (0..=N).map(|i| sieve.mu(i) as i64).collect::<Vec<_>>();
+    assert_eq!(dirichlet_convolution(&phi_table, &one)[1..], id[1..]);
+    assert_eq!(dirichlet_convolution(&id, &mu_table), phi_table);
+    let mut epsilon = vec![0; N + 1];
+    epsilon[1] = 1;
+    assert_eq!(dirichlet_convolution(&mu_table, &one), epsilon);
+
+    let small = 400;
+    let count = PrimeSum::new(N as u64, |_| 1i64, |x| x as i64);
+    let sum = PrimeSum::new(N as u64, |p| p as i64, |x| x as i64 * (x as i64 + 1) / 2);
+    let du_phi = DuSieve::new(
+        N as u64,
+        phi[..=small].to_vec(),
+        |x| x as i64,
+        |x| x as i64 * (x as i64 + 1) / 2,
+    );
+    let du_mu = DuSieve::new(N as u64, mu[..=small].to_vec(), |x| x as i64, |_| 1);
+
+    // the empty sum
+    assert_eq!(min_25(0, |_| 0i64, |_, _| 1), 0);
+    assert_eq!(min_25(1, |_| 0i64, |_, _| 1), 1);
+
+    for (q, _) in quotient_blocks(N as u64) {
+        let q = q as usize;
+        assert_eq!(du_phi.get(q as u64), phi[q]);
+        assert_eq!(du_mu.get(q as u64), mu[q]);
+        assert_eq!(
+            hyperbola(q as u64, |_| 1, |_| 1, |x| x as i64, |x| x as i64),
+            d[q]
+        );
+        assert_eq!(
+            hyperbola(
+                q as u64,
+                |x| x as i64,
+                |x| mu[x as usize] - mu[x as usize - 1],
+                |x| x as i64 * (x as i64 + 1) / 2,
+                |x| mu[x as usize],
+            ),
+            phi[q]
+        );
+
+        // prime sums at ⌊q / k⌋ are available since ⌊⌊N / a⌋ / b⌋ = ⌊N / ab⌋
+        assert_eq!(
+            min_25(
+                q as u64,
+                |x| sum.get(x) - count.get(x),
+                |p, e| (p as i64 - 1) * (p as i64).pow(e - 1),
+            ),
+            phi[q]
+        );
+        assert_eq!(
+            min_25(
+                q as u64,
+                |x| -count.get(x),
+                |_, e| if e == 1 { -1 } else { 0 }
+            ),
+            mu[q]
+        );
+        assert_eq!(
+            min_25(q as u64, |x| 2 * count.get(x), |_, e| e as i64 + 1),
+            d[q]
+        );
+    }
+}
@@ -1,12 +1,69 @@
+use crate::pollard_rho::pollard_rho;
 use std::collections::btree_map::{BTreeMap, IntoIter};
+use std::ops::Mul;
 
-#[derive(Debug, Clone)]
+/// Prime factorization as a map from each prime to its exponent
+///
+/// ```
+/// use prime::factorize::Factorization;
+///
+/// let a = 360u64.factorize().unwrap();
+/// assert_eq!(a.divisor_count(), 24);
+/// assert_eq!(a.divisor_sum(), 1170);
+/// assert_eq!(a.totient(), 96);
+///
+/// let b = 84u64.factorize().unwrap();
+/// assert_eq!(a.gcd(&b).value(), 12);
+/// assert_eq!(a.lcm(&b).value(), 2520);
+/// assert_eq!((a * b).value(), 30240);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PrimeFactors<T>(pub BTreeMap<T, usize>);
 
 impl<T> PrimeFactors<T> {
-    pub fn primt_factors(self) -> BTreeMap<T, usize> {
+    pub fn prime_factors(self) -> BTreeMap<T, usize> {
         self.0
     }
+
+    #[deprecated(note = "use `prime_factors` instead")]
+    pub fn primt_factors(self) -> BTreeMap<T, usize> {
+        self.prime_factors()
+    }
+}
+
+impl<T: Ord + Clone> PrimeFactors<T> {
+    /// Returns the factorization of the greatest common divisor
+    pub fn gcd(&self, other: &Self) -> Self {
+        PrimeFactors(
+            self.0
+                .iter()
+                .filter_map(|(p, &c)| other.0.get(p).map(|&d| (p.clone(), c.min(d))))
+                .collect(),
+        )
+    }
+
+    /// Returns the factorization of the least common multiple
+    pub fn lcm(&self, other: &Self) -> Self {
+        let mut factors = self.0.clone();
+        for (p, &c) in other.0.iter() {
+            let e = factors.entry(p.clone()).or_insert(0);
+            *e = (*e).max(c);
+        }
+        PrimeFactors(factors)
+    }
+}
+
+impl<T: Ord> Mul for PrimeFactors<T> {
+    type Output = Self;
+
+    /// Multiplies the integers by adding the exponents
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(mut self, rhs: Self) -> Self {
+        for (p, c) in rhs.0 {
+            *self.0.entry(p).or_insert(0) += c;
+        }
+        self
+    }
 }
 
 impl<T> IntoIterator for PrimeFactors<T> {
@@ -15,16 +72,87 @@ impl<T> IntoIterator for PrimeFactors<T> {
     type IntoIter = IntoIter<T, usize>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.primt_factors().into_iter()
+        self.prime_factors().into_iter()
     }
 }
 
+macro_rules! impl_prime_factors_for_uint {
+    ($($t:ty),*) => {
+        $(
+            impl PrimeFactors<$t> {
+                /// Returns the integer which has this factorization
+                pub fn value(&self) -> $t {
+                    self.0.iter().map(|(&p, &c)| p.pow(c as u32)).product()
+                }
+
+                /// Returns all the divisors in ascending order
+                ///
+                /// Complexity: `O(d(n) log d(n))`
+                pub fn divisors(&self) -> Vec<$t> {
+                    let mut divisors = vec![1];
+                    for (&p, &c) in self.0.iter() {
+                        let len = divisors.len();
+                        let mut q = 1;
+                        for _ in 0..c {
+                            q *= p;
+                            for i in 0..len {
+                                divisors.push(divisors[i] * q);
+                            }
+                        }
+                    }
+                    divisors.sort_unstable();
+                    divisors
+                }
+
+                /// Returns the number of divisors `d(n)`
+                pub fn divisor_count(&self) -> usize {
+                    self.0.values().map(|&c| c + 1).product()
+                }
+
+                /// Returns the sum of divisors `σ(n)`
+                pub fn divisor_sum(&self) -> $t {
+                    self.0
+                        .iter()
+                        .map(|(&p, &c)| (0..c).fold(1, |acc, _| acc * p + 1))
+                        .product()
+                }
+
+                /// Returns Euler's totient `φ(n)`
+                pub fn totient(&self) -> $t {
+                    self.0
+                        .iter()
+                        .map(|(&p, &c)| p.pow(c as u32 - 1) * (p - 1))
+                        .product()
+                }
+
+                /// Returns the Möbius function `μ(n)`
+                pub fn mobius(&self) -> i8 {
+                    if self.0.values().any(|&c| c > 1) {
+                        0
+                    } else if self.0.len() % 2 == 0 {
+                        1
+                    } else {
+                        -1
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_prime_factors_for_uint!(usize, u8, u16, u32, u64, u128);
+
 #[derive(Debug, Clone, Copy)]
 pub enum FactorizationError {
     Zero,
     One,
 }
 
+/// Integers at least this value are factorized by [Pollard's rho algorythm](crate::pollard_rho::PollardRho)
+const POLLARD_RHO_THRESHOLD: u128 = 1 << 24;
+
+/// Factorizes an integer, by trial division for small integers
+/// and by Pollard's rho algorythm for large ones.
 pub trait Factorization: Sized {
     fn factorize(&self) -> Result<PrimeFactors<Self>, FactorizationError>;
 }
@@ -38,12 +166,19 @@ macro_rules! impl_factorize_for_uint {
                         Err(FactorizationError::Zero)
                     } else if self == &1 {
                         Err(FactorizationError::One)
+                    } else if *self as u128 >= POLLARD_RHO_THRESHOLD {
+                        Ok(PrimeFactors(
+                            pollard_rho(*self as u128)
+                                .into_iter()
+                                .map(|(p, c)| (p as $t, c))
+                                .collect(),
+                        ))
                     } else {
-                        let mut primt_factors = BTreeMap::new();
+                        let mut prime_factors = BTreeMap::new();
                         let mut x = self.clone();
                         let mut d = 2;
                         loop {
-                            if d * d > x {
+                            if d > x / d {
                                 break;
                             }
                             let mut c = 0usize;
@@ -52,16 +187,16 @@ macro_rules! impl_factorize_for_uint {
                                 c += 1;
                             }
                             if c > 0 {
-                                primt_factors.insert(d, c);
+                                prime_factors.insert(d, c);
                             }
                             d += 1;
                         }
 
                         if x > 1 {
-                            primt_factors.insert(x, 1);
+                            prime_factors.insert(x, 1);
                         }
 
-                        Ok(PrimeFactors(primt_factors))
+                        Ok(PrimeFactors(prime_factors))
                     }
                 }
             }
@@ -70,3 +205,43 @@ macro_rules! impl_factorize_for_uint {
 }
 
 impl_factorize_for_uint!(usize, u8, u16, u32, u64, u128);
+
+#[test]
+fn prime_factors_test() {
+    use crate::trial_division::TrialDivision;
+
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+
+    for n in 2..2000u64 {
+        let factors = n.factorize().unwrap();
+        let divisors = n.factors();
+        assert_eq!(factors.value(), n);
+        assert_eq!(factors.divisors(), divisors);
+        assert_eq!(factors.divisor_count(), divisors.len());
+        assert_eq!(factors.divisor_sum(), divisors.iter().sum::<u64>());
+        assert_eq!(
+            factors.totient(),
+            (1..=n).filter(|&k| gcd(n, k) == 1).count() as u64
+        );
+        let square_free = divisors[1..].iter().all(|&d| !n.is_multiple_of(d * d));
+        let mobius = match (square_free, factors.0.len() % 2) {
+            (false, _) => 0,
+            (true, 0) => 1,
+            (true, _) => -1,
+        };
+        assert_eq!(factors.mobius(), mobius);
+
+        for m in (2..200u64).step_by(7) {
+            let other = m.factorize().unwrap();
+            let g = gcd(n, m);
+            assert_eq!(factors.gcd(&other).value(), g);
+            assert_eq!(factors.lcm(&other).value(), n / g * m);
+            assert_eq!((factors.clone() * other).value(), n * m);
+        }
+    }
+}
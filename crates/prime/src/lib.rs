@@ -1,4 +1,9 @@
+pub mod dirichlet;
 pub mod factorize;
 pub mod miller_rabin;
+pub mod modular;
+pub mod pollard_rho;
+pub mod prime_count;
+pub mod quotient;
 pub mod sieve;
 pub mod trial_division;
@@ -8,15 +8,17 @@ fn miller_rabin<R: Rng>(n: u128, k: usize, rng: &mut R) -> bool {
     } else {
         let s = (n - 1).trailing_zeros();
         let d = (n - 1) >> s;
+        let mont = Montgomery128::new(n);
+        let one = mont.transform(1);
+        let minus_one = mont.transform(n - 1);
 
         for _ in 0..k {
             let a = rng.gen_range(1..n);
-            let mut y = mod_pow(a as u128, d as u128, n as u128);
-            if y != 1 && y != n - 1 && {
+            let mut y = mont.pow(mont.transform(a), d);
+            if y != one && y != minus_one && {
                 (0..s).all(|_| {
-                    y *= y;
-                    y %= n;
-                    y != n - 1
+                    y = mont.mul(y, y);
+                    y != minus_one
                 })
             } {
                 return false;
@@ -29,13 +31,15 @@ fn miller_rabin<R: Rng>(n: u128, k: usize, rng: &mut R) -> bool {
 
 /// # Miller–Rabin algorythm
 ///
-/// Seehttps://en.wikipedia.org/wiki/Miller%e2%80%93Rabin_primality_test
+/// See https://en.wikipedia.org/wiki/Miller%e2%80%93Rabin_primality_test
 ///
 /// Estimate whether the number is prime or composite.
 /// This algorythm is **non-deterministic**,
 /// an argument `accuracy` is used to indicate the accuracy of the judgement.
 /// Possibility of incorrect judge with `accuracy = k` is 4 ^ -k at most.
 ///
+/// For integers less than `2^64`, [`is_prime`] gives the deterministic answer.
+///
 /// Complexity: `O(k × log3 n)`
 pub trait MillerRabin {
     /// Returns whether `self` is a prime number
@@ -56,17 +60,493 @@ macro_rules! impl_miller_rabin_for_uint {
 
 impl_miller_rabin_for_uint!(usize, u32, u64, u128);
 
-fn mod_pow(mut x: u128, mut y: u128, m: u128) -> u128 {
+/// Montgomery multiplication modulo an odd integer `n < 2^64`
+///
+/// See https://en.wikipedia.org/wiki/Montgomery_modular_multiplication
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Montgomery {
+    n: u64,
+    /// `n^-1 mod 2^64`
+    n_inv: u64,
+    /// `2^128 mod n`
+    r2: u64,
+}
+
+impl Montgomery {
+    pub(crate) fn new(n: u64) -> Self {
+        debug_assert!(n & 1 == 1);
+
+        // Newton's method, each step doubles the number of correct bits
+        let mut n_inv = n;
+        for _ in 0..5 {
+            n_inv = n_inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(n_inv)));
+        }
+        let r = ((1u128 << 64) % n as u128) as u64;
+        let r2 = (r as u128 * r as u128 % n as u128) as u64;
+
+        Self { n, n_inv, r2 }
+    }
+
+    /// Returns `t / 2^64 mod n`, for `t < n * 2^64`
+    fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.n_inv);
+        let mn = ((m as u128 * self.n as u128) >> 64) as u64;
+        let hi = (t >> 64) as u64;
+        if hi >= mn {
+            hi - mn
+        } else {
+            hi.wrapping_sub(mn).wrapping_add(self.n)
+        }
+    }
+
+    /// Converts `x` into Montgomery form
+    pub(crate) fn transform(&self, x: u64) -> u64 {
+        self.reduce(x as u128 * self.r2 as u128)
+    }
+
+    /// Multiplies two integers in Montgomery form
+    pub(crate) fn mul(&self, x: u64, y: u64) -> u64 {
+        self.reduce(x as u128 * y as u128)
+    }
+
+    /// Adds two integers in Montgomery form
+    pub(crate) fn add(&self, x: u64, y: u64) -> u64 {
+        if x >= self.n - y {
+            x - (self.n - y)
+        } else {
+            x + y
+        }
+    }
+
+    pub(crate) fn pow(&self, mut x: u64, mut y: u64) -> u64 {
+        let mut res = self.transform(1);
+        while y != 0 {
+            if y & 1 != 0 {
+                res = self.mul(res, x);
+            }
+            x = self.mul(x, x);
+            y >>= 1;
+        }
+        res
+    }
+}
+
+/// Returns `(hi, lo)` where `x * y = hi * 2^128 + lo`
+fn mul_wide(x: u128, y: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (x1, x0) = (x >> 64, x & MASK);
+    let (y1, y0) = (y >> 64, y & MASK);
+    let p00 = x0 * y0;
+    let p01 = x0 * y1;
+    let p10 = x1 * y0;
+    let p11 = x1 * y1;
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Montgomery multiplication modulo an odd integer `n < 2^128`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Montgomery128 {
+    n: u128,
+    /// `n^-1 mod 2^128`
+    n_inv: u128,
+    /// `2^256 mod n`
+    r2: u128,
+}
+
+impl Montgomery128 {
+    pub(crate) fn new(n: u128) -> Self {
+        debug_assert!(n & 1 == 1);
+
+        let mut n_inv = n;
+        for _ in 0..6 {
+            n_inv = n_inv.wrapping_mul(2u128.wrapping_sub(n.wrapping_mul(n_inv)));
+        }
+        // 2^128 mod n, then doubled 128 times
+        let mut r2 = (u128::MAX % n + 1) % n;
+        for _ in 0..128 {
+            r2 = if r2 >= n - r2 { r2 - (n - r2) } else { r2 + r2 };
+        }
+
+        Self { n, n_inv, r2 }
+    }
+
+    /// Returns `(hi * 2^128 + lo) / 2^128 mod n`, for `hi < n`
+    fn reduce(&self, (hi, lo): (u128, u128)) -> u128 {
+        let m = lo.wrapping_mul(self.n_inv);
+        let (mn, _) = mul_wide(m, self.n);
+        if hi >= mn {
+            hi - mn
+        } else {
+            hi.wrapping_sub(mn).wrapping_add(self.n)
+        }
+    }
+
+    /// Converts `x` into Montgomery form
+    pub(crate) fn transform(&self, x: u128) -> u128 {
+        self.reduce(mul_wide(x % self.n, self.r2))
+    }
+
+    /// Multiplies two integers in Montgomery form
+    pub(crate) fn mul(&self, x: u128, y: u128) -> u128 {
+        self.reduce(mul_wide(x, y))
+    }
+
+    /// Adds two integers in Montgomery form
+    pub(crate) fn add(&self, x: u128, y: u128) -> u128 {
+        if x >= self.n - y {
+            x - (self.n - y)
+        } else {
+            x + y
+        }
+    }
+
+    pub(crate) fn pow(&self, mut x: u128, mut y: u128) -> u128 {
+        let mut res = self.transform(1);
+        while y != 0 {
+            if y & 1 != 0 {
+                res = self.mul(res, x);
+            }
+            x = self.mul(x, x);
+            y >>= 1;
+        }
+        res
+    }
+}
+
+/// # Deterministic Miller–Rabin algorythm
+///
+/// Returns whether `n` is a prime number.
+/// For `n < 2^64`, testing with the seven bases found by Jim Sinclair is enough.
+///
+/// See https://miller-rabin.appspot.com/
+///
+/// Complexity: `O(log n)`
+///
+/// ```
+/// use prime::miller_rabin::is_prime;
+///
+/// assert!(is_prime(998244353));
+/// assert!(!is_prime(3215031751)); // strong pseudoprime to bases 2, 3, 5 and 7
+/// assert!(is_prime(18446744073709551557)); // the largest prime less than 2^64
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+    if n < 64 {
+        return (1u64 << n) & 0x28208a20a08a28ac != 0;
+    }
+    if n & 1 == 0 {
+        return false;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mont = Montgomery::new(n);
+    let one = mont.transform(1);
+    let minus_one = mont.transform(n - 1);
+
+    BASES.iter().all(|&a| {
+        let a = a % n;
+        if a == 0 {
+            return true;
+        }
+
+        let mut y = mont.pow(mont.transform(a), d);
+        if y == one || y == minus_one {
+            return true;
+        }
+        (1..s).any(|_| {
+            y = mont.mul(y, y);
+            y == minus_one
+        })
+    })
+}
+
+impl Montgomery128 {
+    /// Subtracts two integers in Montgomery form
+    fn sub(&self, x: u128, y: u128) -> u128 {
+        if x >= y {
+            x - y
+        } else {
+            x + (self.n - y)
+        }
+    }
+
+    /// Halves an integer, which works in Montgomery form as well
+    fn half(&self, x: u128) -> u128 {
+        if x & 1 == 0 {
+            x >> 1
+        } else {
+            (x >> 1) + (self.n >> 1) + 1
+        }
+    }
+
+    /// Converts a signed integer into Montgomery form
+    fn transform_signed(&self, x: i128) -> u128 {
+        if x >= 0 {
+            self.transform(x as u128)
+        } else {
+            self.sub(0, self.transform(x.unsigned_abs()))
+        }
+    }
+}
+
+/// Returns whether odd `n` is a strong probable prime to base `a`
+fn is_strong_probable_prime(mont: &Montgomery128, n: u128, a: u128) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let one = mont.transform(1);
+    let minus_one = mont.transform(n - 1);
+
+    let mut y = mont.pow(mont.transform(a), d);
+    if y == one || y == minus_one {
+        return true;
+    }
+    (1..s).any(|_| {
+        y = mont.mul(y, y);
+        y == minus_one
+    })
+}
+
+/// Returns the Jacobi symbol `(a / n)` for odd `n`
+fn jacobi(mut a: u128, mut n: u128) -> i32 {
     let mut res = 1;
-    while y != 0 {
-        if y & 1 != 0 {
-            res *= x;
-            res %= m;
+    a %= n;
+    while a != 0 {
+        let t = a.trailing_zeros();
+        a >>= t;
+        if t & 1 == 1 && (n & 7 == 3 || n & 7 == 5) {
+            res = -res;
+        }
+        if a & 3 == 3 && n & 3 == 3 {
+            res = -res;
+        }
+        (a, n) = (n % a, a);
+    }
+    if n == 1 {
+        res
+    } else {
+        0
+    }
+}
+
+/// Returns whether odd `n` is a strong Lucas probable prime,
+/// with the parameters chosen by Selfridge's method A
+///
+/// See https://en.wikipedia.org/wiki/Lucas_pseudoprime#Strong_Lucas_pseudoprimes
+fn is_strong_lucas_probable_prime(mont: &Montgomery128, n: u128) -> bool {
+    // D would never be found for a perfect square
+    let r = n.isqrt();
+    if r * r == n {
+        return false;
+    }
+
+    // D = 5, -7, 9, -11, ... with (D / n) = -1, P = 1, Q = (1 - D) / 4
+    let mut d = 5i128;
+    loop {
+        let a = if d > 0 {
+            d as u128
+        } else {
+            n - d.unsigned_abs()
+        };
+        match jacobi(a, n) {
+            -1 => break,
+            // n is greater than |D| and has a common factor with it
+            0 => return false,
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+    let q = mont.transform_signed((1 - d) / 4);
+    let d = mont.transform_signed(d);
+
+    // n + 1 = k 2^s, computing U_k, V_k and Q^k from the top bit
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+    let mut u = mont.transform(1);
+    let mut v = u;
+    let mut qk = q;
+    for i in (0..k.ilog2()).rev() {
+        u = mont.mul(u, v);
+        v = mont.sub(mont.mul(v, v), mont.add(qk, qk));
+        qk = mont.mul(qk, qk);
+        if k >> i & 1 == 1 {
+            (u, v) = (
+                mont.half(mont.add(u, v)),
+                mont.half(mont.add(mont.mul(d, u), v)),
+            );
+            qk = mont.mul(qk, q);
         }
-        x *= x;
-        x %= m;
-        y >>= 1;
     }
 
-    res
+    if u == 0 || v == 0 {
+        return true;
+    }
+    (1..s).any(|_| {
+        v = mont.sub(mont.mul(v, v), mont.add(qk, qk));
+        qk = mont.mul(qk, qk);
+        v == 0
+    })
+}
+
+/// Returns whether `n` is a prime number.
+///
+/// For `n < 3317044064679887385961981`, the first thirteen primes as bases are enough,
+/// and for `n < 2^64` [`is_prime`] is used.
+/// Beyond it, the Baillie–PSW test is used, which has no known counterexamples.
+///
+/// See https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test
+pub(crate) fn is_prime_u128(n: u128) -> bool {
+    const BASES: [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+    // The smallest strong pseudoprime to all the bases above
+    const PSI_13: u128 = 3317044064679887385961981;
+
+    if n <= u64::MAX as u128 {
+        return is_prime(n as u64);
+    }
+    if n & 1 == 0 {
+        return false;
+    }
+
+    let mont = Montgomery128::new(n);
+    if n < PSI_13 {
+        BASES.iter().all(|&a| is_strong_probable_prime(&mont, n, a))
+    } else {
+        is_strong_probable_prime(&mont, n, 2) && is_strong_lucas_probable_prime(&mont, n)
+    }
+}
+
+#[test]
+fn is_prime_test() {
+    use crate::trial_division::TrialDivision;
+
+    for n in 0..100000u64 {
+        assert_eq!(is_prime(n), TrialDivision::is_prime(&n), "{}", n);
+    }
+
+    // Carmichael numbers and strong pseudoprimes
+    for n in [
+        561,
+        3215031751,
+        4759123141,
+        1122004669633,
+        3825123056546413051,
+    ] {
+        assert!(!is_prime(n), "{}", n);
+    }
+
+    for n in [
+        (1u64 << 61) - 1,
+        1000000007,
+        998244353,
+        18446744073709551557,
+    ] {
+        assert!(is_prime(n), "{}", n);
+    }
+    assert!(!is_prime(u64::MAX));
+    assert!(!is_prime(4294967291 * 4294967279));
+}
+
+#[test]
+fn miller_rabin_u128_test() {
+    let mut rng = rand::thread_rng();
+
+    assert!(((1u128 << 89) - 1).is_prime(20, &mut rng));
+    assert!(((1u128 << 127) - 1).is_prime(20, &mut rng));
+    assert!(!(((1u128 << 61) - 1) * ((1u128 << 31) - 1)).is_prime(20, &mut rng));
+    assert!(!(18446744073709551557u128 * 18446744073709551557).is_prime(20, &mut rng));
+    assert!(18446744073709551557u64.is_prime(20, &mut rng));
+}
+
+#[test]
+fn montgomery128_test() {
+    use rand::Rng;
+
+    // shift-and-add, independent of the Montgomery form
+    let add_mod = |x: u128, y: u128, n: u128| if x >= n - y { x - (n - y) } else { x + y };
+    let mul_mod = |mut x: u128, mut y: u128, n: u128| {
+        let mut res = 0;
+        while y != 0 {
+            if y & 1 != 0 {
+                res = add_mod(res, x, n);
+            }
+            x = add_mod(x, x, n);
+            y >>= 1;
+        }
+        res
+    };
+
+    let mut rng = rand::thread_rng();
+    for i in 0..3000 {
+        // moduli of every size, up to and including u128::MAX
+        let n = match i % 3 {
+            0 => rng.gen::<u128>() | 1,
+            1 => {
+                let bits = rng.gen_range(1..128);
+                rng.gen_range(1..1u128 << bits) | 1
+            }
+            _ => u128::MAX - 2 * rng.gen_range(0..1000u128),
+        };
+        let mont = Montgomery128::new(n);
+        let x = rng.gen::<u128>();
+        let y = rng.gen::<u128>();
+        let (tx, ty) = (mont.transform(x), mont.transform(y));
+        let expected = mul_mod(x % n, y % n, n);
+        assert_eq!(
+            mont.mul(mont.mul(tx, ty), 1),
+            expected,
+            "{} * {} mod {}",
+            x,
+            y,
+            n
+        );
+        assert_eq!(mont.mul(mont.add(tx, ty), 1), add_mod(x % n, y % n, n));
+
+        let e = rng.gen_range(0..64u128);
+        let power = (0..e).fold(1 % n, |acc, _| mul_mod(acc, x % n, n));
+        assert_eq!(mont.mul(mont.pow(tx, e), 1), power);
+    }
+
+    assert!(is_prime_u128((1 << 89) - 1));
+    assert!(is_prime_u128((1 << 127) - 1));
+    assert!(!is_prime_u128(((1 << 61) - 1) * ((1 << 31) - 1)));
+    assert!(!is_prime_u128(18446744073709551557 * 18446744073709551557));
+}
+
+#[test]
+fn baillie_psw_test() {
+    use crate::trial_division::TrialDivision;
+    use rand::Rng;
+
+    // strong Lucas pseudoprimes below 10^5
+    const PSEUDOPRIMES: [u128; 12] = [
+        5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519, 75077, 97439,
+    ];
+    for n in (101..100000u128).step_by(2) {
+        let lucas = is_strong_lucas_probable_prime(&Montgomery128::new(n), n);
+        let prime = TrialDivision::is_prime(&(n as u64));
+        assert_eq!(lucas, prime || PSEUDOPRIMES.contains(&n), "{}", n);
+    }
+
+    // Baillie–PSW agrees with the deterministic bases below PSI_13
+    let bpsw = |n: u128| {
+        let mont = Montgomery128::new(n);
+        is_strong_probable_prime(&mont, n, 2) && is_strong_lucas_probable_prime(&mont, n)
+    };
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let n = rng.gen_range(1u128 << 64..1 << 80) | 1;
+        assert_eq!(bpsw(n), is_prime_u128(n), "{}", n);
+    }
+
+    // strong pseudoprime to the first thirteen primes
+    assert!(!is_prime_u128(3317044064679887385961981));
+    assert!(is_prime_u128(3317044064679887385962123));
+    assert!(is_prime_u128(340282366920938463463374607431768211297));
+    assert!(!is_prime_u128(170141220222336638350339947157455380321));
+    assert!(!is_prime_u128(9223372036854775837 * 9223372036854775837));
 }
@@ -0,0 +1,188 @@
+/// # Extended Euclidean algorythm
+///
+/// See https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+///
+/// Returns `(g, x, y)` where `g = gcd(a, b) ≥ 0` and `a x + b y = g`,
+/// with `|x| ≤ |b / g|` and `|y| ≤ |a / g|`.
+///
+/// Complexity: `O(log min(a, b))`
+///
+/// ```
+/// use prime::modular::ext_gcd;
+///
+/// assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+/// assert_eq!(ext_gcd(-4, 6), (2, 1, 1));
+/// ```
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Returns `x` in `[0, m)` where `a x ≡ 1 (mod m)`,
+/// or `None` if `a` and `m` are not coprime.
+/// `m` does not need to be a prime number.
+///
+/// Complexity: `O(log m)`
+///
+/// ```
+/// use prime::modular::inv_mod;
+///
+/// assert_eq!(inv_mod(7, 12), Some(7));
+/// assert_eq!(inv_mod(-5, 12), Some(7));
+/// assert_eq!(inv_mod(4, 12), None);
+/// ```
+pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0);
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// # Modular exponentiation
+///
+/// See https://en.wikipedia.org/wiki/Modular_exponentiation
+///
+/// Returns `x^n mod m` by binary exponentiation, without overflow for any `m > 0`.
+/// It is a `const fn`, so that constants like primitive roots can be computed at compile time.
+///
+/// Complexity: `O(log n)`
+///
+/// ```
+/// use prime::modular::pow_mod;
+///
+/// assert_eq!(pow_mod(3, 4, 7), 4);
+/// assert_eq!(pow_mod(5, 0, 1), 0);
+/// assert_eq!(pow_mod(u64::MAX - 1, 2, u64::MAX), 1);
+/// ```
+pub const fn pow_mod(x: u64, mut n: u64, m: u64) -> u64 {
+    assert!(m > 0);
+    let m = m as u128;
+    let mut x = x as u128 % m;
+    let mut res = 1 % m;
+    while n != 0 {
+        if n & 1 != 0 {
+            res = res * x % m;
+        }
+        x = x * x % m;
+        n >>= 1;
+    }
+    res as u64
+}
+
+/// # Chinese remainder theorem
+///
+/// See https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+///
+/// Solves the system `x ≡ r_i (mod m_i)` and returns `(x, lcm m_i)` with `0 ≤ x < lcm m_i`.
+/// The moduli need not be pairwise coprime;
+/// `None` is returned if the system is inconsistent.
+/// The least common multiple must fit in `i64`.
+///
+/// Complexity: `O(n log lcm m_i)`
+///
+/// ```
+/// use prime::modular::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// assert_eq!(crt(&[]), Some((0, 1)));
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut r0, mut m0) = (0i64, 1i64);
+    for &(r1, m1) in congruences {
+        assert!(m1 > 0);
+        let r1 = r1.rem_euclid(m1);
+
+        // x = r0 + m0 t, m0 t ≡ r1 - r0 (mod m1)
+        let (g, p, _) = ext_gcd(m0, m1);
+        if (r1 - r0) % g != 0 {
+            return None;
+        }
+        let u = m1 / g;
+        let t = ((r1 - r0) / g) as i128 * p as i128 % u as i128;
+        let lcm = m0 as i128 * u as i128;
+        r0 = (r0 as i128 + m0 as i128 * t).rem_euclid(lcm) as i64;
+        m0 = lcm as i64;
+    }
+
+    Some((r0, m0))
+}
+
+#[test]
+fn modular_test() {
+    use rand::Rng;
+
+    let gcd = |mut a: i64, mut b: i64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a.abs()
+    };
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let a = rng.gen_range(-1_000_000_000_000..1_000_000_000_000);
+        let b = rng.gen_range(-1_000_000_000_000..1_000_000_000_000);
+        let (g, x, y) = ext_gcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+
+        let m = rng.gen_range(1..1_000_000_000);
+        match inv_mod(a, m) {
+            Some(x) => {
+                assert!((0..m).contains(&x));
+                assert_eq!((a as i128 * x as i128).rem_euclid(m as i128), 1 % m as i128);
+            }
+            None => assert_ne!(gcd(a, m), 1),
+        }
+    }
+
+    for _ in 0..1000 {
+        let m = rng.gen_range(1..=u64::MAX);
+        let x = rng.gen::<u64>();
+        let n = rng.gen_range(0..64);
+        let expected = (0..n).fold(1 % m as u128, |acc, _| {
+            acc * (x as u128 % m as u128) % m as u128
+        });
+        assert_eq!(pow_mod(x, n, m) as u128, expected);
+    }
+
+    for _ in 0..1000 {
+        let n = rng.gen_range(0..4);
+        let congruences = (0..n)
+            .map(|_| {
+                let m = rng.gen_range(1..30);
+                (rng.gen_range(-100..100), m)
+            })
+            .collect::<Vec<_>>();
+        let lcm = congruences
+            .iter()
+            .fold(1, |acc, &(_, m)| acc / gcd(acc, m) * m);
+        let expected =
+            (0..lcm).find(|&x| congruences.iter().all(|&(r, m)| (x - r).rem_euclid(m) == 0));
+        assert_eq!(crt(&congruences), expected.map(|x| (x, lcm)));
+    }
+
+    let (r, m) = crt(&[(1, 1_000_000_007), (2, 998_244_353), (3, 4)]).unwrap();
+    assert_eq!(m, 1_000_000_007 * 998_244_353 * 4);
+    assert_eq!(r % 1_000_000_007, 1);
+    assert_eq!(r % 998_244_353, 2);
+    assert_eq!(r % 4, 3);
+}
@@ -0,0 +1,235 @@
+use crate::factorize::{FactorizationError, PrimeFactors};
+use crate::miller_rabin::{is_prime, is_prime_u128, Montgomery, Montgomery128};
+use std::collections::BTreeMap;
+
+/// # Pollard's rho algorythm
+///
+/// See https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+///
+/// Factorizes an integer with Brent's cycle detection,
+/// judging primality by deterministic Miller–Rabin.
+/// For integers at least `2^64`, primality is judged with thirteen fixed bases,
+/// which is deterministic below `3.3 × 10^24`.
+///
+/// Complexity: `O(n^1/4)` expected
+///
+/// ```
+/// use prime::pollard_rho::PollardRho;
+///
+/// let factors = (999999999999999989u64 * 3 * 3).pollard_rho().unwrap();
+/// assert_eq!(
+///     factors.prime_factors().into_iter().collect::<Vec<_>>(),
+///     vec![(3, 2), (999999999999999989, 1)]
+/// );
+/// ```
+pub trait PollardRho: Sized {
+    fn pollard_rho(&self) -> Result<PrimeFactors<Self>, FactorizationError>;
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+macro_rules! impl_find_factor {
+    ($name:ident, $t:ty, $mont:ty, $gcd:ident) => {
+        /// Returns a non-trivial factor of an odd composite number `n`
+        fn $name(n: $t) -> $t {
+            const M: usize = 128;
+
+            let mont = <$mont>::new(n);
+            let diff = |x: $t, y: $t| if x >= y { x - y } else { y - x };
+
+            for c in 1.. {
+                let c = mont.transform(c);
+                let f = |x: $t| mont.add(mont.mul(x, x), c);
+
+                let (mut x, mut y, mut ys) = (0, mont.transform(2), 0);
+                let mut q = mont.transform(1);
+                let mut g = 1;
+                let mut r = 1;
+                while g == 1 {
+                    x = y;
+                    for _ in 0..r {
+                        y = f(y);
+                    }
+                    let mut k = 0;
+                    while k < r && g == 1 {
+                        ys = y;
+                        for _ in 0..M.min(r - k) {
+                            y = f(y);
+                            q = mont.mul(q, diff(x, y));
+                        }
+                        g = $gcd(q, n);
+                        k += M;
+                    }
+                    r <<= 1;
+                }
+
+                if g == n {
+                    g = 1;
+                    while g == 1 {
+                        ys = f(ys);
+                        g = $gcd(diff(x, ys), n);
+                    }
+                }
+                if g != n {
+                    return g;
+                }
+            }
+            unreachable!()
+        }
+    };
+}
+
+impl_find_factor!(find_factor_u64, u64, Montgomery, gcd_u64);
+impl_find_factor!(find_factor_u128, u128, Montgomery128, gcd_u128);
+
+fn factorize_u64(n: u64, factors: &mut BTreeMap<u64, usize>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        *factors.entry(n).or_insert(0) += 1;
+        return;
+    }
+
+    let d = find_factor_u64(n);
+    factorize_u64(d, factors);
+    factorize_u64(n / d, factors);
+}
+
+fn factorize_u128(n: u128, factors: &mut BTreeMap<u128, usize>) {
+    if n <= u64::MAX as u128 {
+        let mut small = BTreeMap::new();
+        factorize_u64(n as u64, &mut small);
+        for (p, c) in small {
+            *factors.entry(p as u128).or_insert(0) += c;
+        }
+        return;
+    }
+    if is_prime_u128(n) {
+        *factors.entry(n).or_insert(0) += 1;
+        return;
+    }
+
+    let d = find_factor_u128(n);
+    factorize_u128(d, factors);
+    factorize_u128(n / d, factors);
+}
+
+/// Returns the prime factors of `n ≥ 2`
+pub(crate) fn pollard_rho(mut n: u128) -> BTreeMap<u128, usize> {
+    let mut factors = BTreeMap::new();
+
+    // Brent's method needs an odd modulus, and small factors are quicker by division
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut c = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            c += 1;
+        }
+        if c > 0 {
+            factors.insert(p, c);
+        }
+    }
+    factorize_u128(n, &mut factors);
+
+    factors
+}
+
+macro_rules! impl_pollard_rho_for_uint {
+    ($($t:ty),*) => {
+        $(
+            impl PollardRho for $t {
+                fn pollard_rho(&self) -> Result<PrimeFactors<Self>, FactorizationError> {
+                    if *self == 0 {
+                        Err(FactorizationError::Zero)
+                    } else if *self == 1 {
+                        Err(FactorizationError::One)
+                    } else {
+                        Ok(PrimeFactors(
+                            pollard_rho(*self as u128)
+                                .into_iter()
+                                .map(|(p, c)| (p as $t, c))
+                                .collect(),
+                        ))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_pollard_rho_for_uint!(usize, u64, u128);
+
+#[test]
+fn pollard_rho_test() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let n = rng.gen_range(2..u64::MAX);
+        let mut factors = BTreeMap::new();
+        for (p, c) in n.pollard_rho().unwrap() {
+            assert!(is_prime(p));
+            factors.insert(p, c);
+        }
+        assert_eq!(n, factors.iter().map(|(p, &c)| p.pow(c as u32)).product());
+    }
+
+    let factors = |n: u128| n.pollard_rho().unwrap().into_iter().collect::<Vec<_>>();
+    // strong pseudoprime to the first thirteen primes
+    assert_eq!(
+        factors(3317044064679887385961981),
+        vec![(1287836182261, 1), (2575672364521, 1)]
+    );
+    assert_eq!(
+        factors(u64::MAX as u128),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (6700417, 1)
+        ]
+    );
+    assert_eq!(
+        factors(u128::MAX),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1)
+        ]
+    );
+    assert_eq!(
+        factors(1099511627791 * 1099511627689),
+        vec![(1099511627689, 1), (1099511627791, 1)]
+    );
+    assert_eq!(
+        factors(999999999999999989 * 1000000007 * 1000000007),
+        vec![(1000000007, 2), (999999999999999989, 1)]
+    );
+    assert_eq!(
+        factors(4611686018427387847 * 35184372088777),
+        vec![(35184372088777, 1), (4611686018427387847, 1)]
+    );
+    assert_eq!(factors((1 << 127) - 1), vec![((1 << 127) - 1, 1)]);
+}
@@ -0,0 +1,144 @@
+use std::ops::{Add, Mul, Sub};
+
+/// # Lucy_Hedgehog algorythm
+///
+/// See https://projecteuler.net/thread=10;page=5#111677
+///
+/// Computes `Σ f(p)` over the primes `p ≤ x`
+/// for every `x` of the form `⌊n / k⌋` at once,
+/// where `f` is a completely multiplicative function.
+/// It requires `f` itself and its prefix sum `Σ_{i=1}^{x} f(i)`.
+///
+/// Complexity: `O(n^3/4)`
+///
+/// ```
+/// use prime::prime_count::PrimeSum;
+///
+/// // sum of primes
+/// let sum = PrimeSum::new(1000000, |p| p as u128, |x| x as u128 * (x as u128 + 1) / 2);
+/// assert_eq!(sum.get(1000000), 37550402023);
+/// assert_eq!(sum.get(1000), 76127);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrimeSum<T> {
+    n: u64,
+    sqrt: u64,
+    /// `small[x]` for `x ≤ √n`
+    small: Vec<T>,
+    /// `large[k]` for `⌊n / k⌋` with `k ≤ √n`
+    large: Vec<T>,
+}
+
+impl<T> PrimeSum<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn new<F, S>(n: u64, f: F, prefix_sum: S) -> Self
+    where
+        F: Fn(u64) -> T,
+        S: Fn(u64) -> T,
+    {
+        let sqrt = n.isqrt();
+        let one = prefix_sum(1);
+        let mut small = (0..=sqrt)
+            .map(|x| prefix_sum(x.max(1)) - one)
+            .collect::<Vec<_>>();
+        let mut large = (0..=sqrt)
+            .map(|k| prefix_sum(n.checked_div(k).unwrap_or(1)) - one)
+            .collect::<Vec<_>>();
+
+        let mut is_composite = vec![false; sqrt as usize + 1];
+        for p in 2..=sqrt {
+            if is_composite[p as usize] {
+                continue;
+            }
+            for m in (p * p..=sqrt).step_by(p as usize) {
+                is_composite[m as usize] = true;
+            }
+
+            let fp = f(p);
+            let sp = small[p as usize - 1];
+            let p2 = p * p;
+
+            // Descending order of x, so that ⌊x / p⌋ is not updated yet
+            for k in 1..=sqrt.min(n / p2) {
+                let kp = k * p;
+                let y = if kp <= sqrt {
+                    large[kp as usize]
+                } else {
+                    small[(n / kp) as usize]
+                };
+                large[k as usize] = large[k as usize] - fp * (y - sp);
+            }
+            for x in (p2..=sqrt).rev() {
+                small[x as usize] = small[x as usize] - fp * (small[(x / p) as usize] - sp);
+            }
+        }
+
+        Self {
+            n,
+            sqrt,
+            small,
+            large,
+        }
+    }
+
+    /// Returns `Σ f(p)` over the primes `p ≤ x`,
+    /// where `x` must be `⌊n / k⌋` for some `k`
+    pub fn get(&self, x: u64) -> T {
+        if x <= self.sqrt {
+            self.small[x as usize]
+        } else {
+            let k = self.n / x;
+            debug_assert_eq!(self.n / k, x);
+            self.large[k as usize]
+        }
+    }
+}
+
+/// Returns the number of primes less than or equal to `n`
+///
+/// Complexity: `O(n^3/4)`
+///
+/// ```
+/// use prime::prime_count::prime_count;
+///
+/// assert_eq!(prime_count(100), 25);
+/// assert_eq!(prime_count(10000000000), 455052511);
+/// ```
+pub fn prime_count(n: u64) -> u64 {
+    PrimeSum::new(n, |_| 1, |x| x).get(n)
+}
+
+#[test]
+fn prime_count_test() {
+    use crate::sieve::eratosthenes::SieveOfEratosthenes;
+
+    const N: u64 = 100000;
+    let sieve = SieveOfEratosthenes::new(N as usize);
+    let primes = sieve.primes();
+    for n in (0..1000).chain([N - 1, N]) {
+        let expected = primes.iter().take_while(|&&p| p as u64 <= n).count();
+        assert_eq!(prime_count(n), expected as u64, "{}", n);
+    }
+
+    let squares = PrimeSum::new(
+        N,
+        |p| p as u128 * p as u128,
+        |x| {
+            let x = x as u128;
+            x * (x + 1) * (2 * x + 1) / 6
+        },
+    );
+    for k in 1..=N {
+        let x = N / k;
+        let expected = primes
+            .iter()
+            .take_while(|&&p| p as u64 <= x)
+            .map(|&p| p as u128 * p as u128)
+            .sum::<u128>();
+        assert_eq!(squares.get(x), expected, "{}", x);
+    }
+
+    assert_eq!(prime_count(1000000000), 50847534);
+}
@@ -0,0 +1,158 @@
+use std::ops::Range;
+
+fn floor_sum_unsigned(mut n: u64, mut m: u64, mut a: u64, mut b: u64) -> u64 {
+    let mut res = 0u64;
+    loop {
+        if a >= m {
+            res = res.wrapping_add((n * n.wrapping_sub(1) / 2).wrapping_mul(a / m));
+            a %= m;
+        }
+        if b >= m {
+            res = res.wrapping_add(n.wrapping_mul(b / m));
+            b %= m;
+        }
+
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        // Count the lattice points by swapping the axes
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    res
+}
+
+/// # Floor sum
+///
+/// See https://atcoder.github.io/ac-library/production/document_en/math.html
+///
+/// Returns `Σ_{i=0}^{n-1} ⌊(a i + b) / m⌋`, where `0 ≤ n < 2^32` and `1 ≤ m < 2^32`.
+/// The result must fit in `i64`.
+///
+/// Complexity: `O(log m)`
+///
+/// ```
+/// use prime::quotient::floor_sum;
+///
+/// assert_eq!(floor_sum(4, 10, 6, 3), 3);
+/// assert_eq!(floor_sum(6, 5, 4, 3), 13);
+/// assert_eq!(floor_sum(3, 4, -5, 2), -3);
+/// ```
+pub fn floor_sum(n: i64, m: i64, mut a: i64, mut b: i64) -> i64 {
+    assert!((0..1 << 32).contains(&n));
+    assert!((1..1 << 32).contains(&m));
+
+    let mut res = 0u64;
+    if a < 0 {
+        let a2 = a.rem_euclid(m);
+        let q = ((a2 - a) / m) as u64;
+        res = res.wrapping_sub((n as u64 * (n as u64).wrapping_sub(1) / 2).wrapping_mul(q));
+        a = a2;
+    }
+    if b < 0 {
+        let b2 = b.rem_euclid(m);
+        let q = ((b2 - b) / m) as u64;
+        res = res.wrapping_sub((n as u64).wrapping_mul(q));
+        b = b2;
+    }
+    res.wrapping_add(floor_sum_unsigned(n as u64, m as u64, a as u64, b as u64)) as i64
+}
+
+/// Iterator over the distinct values of `⌊n / i⌋` for `1 ≤ i ≤ n`,
+/// created by [`quotient_blocks`]
+#[derive(Debug, Clone)]
+pub struct QuotientBlocks {
+    n: u64,
+    i: u64,
+}
+
+impl Iterator for QuotientBlocks {
+    type Item = (u64, Range<u64>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.n {
+            return None;
+        }
+
+        let q = self.n / self.i;
+        let r = self.n / q + 1;
+        let range = self.i..r;
+        self.i = r;
+        Some((q, range))
+    }
+}
+
+/// Returns an iterator over the pairs `(q, l..r)` where `⌊n / i⌋ = q` for all `l ≤ i < r`,
+/// in ascending order of `i`.
+/// There are at most `2√n` such blocks.
+///
+/// `n` must be less than `u64::MAX`, since the last range ends at `n + 1`.
+///
+/// ```
+/// use prime::quotient::quotient_blocks;
+///
+/// assert_eq!(
+///     quotient_blocks(10).collect::<Vec<_>>(),
+///     vec![(10, 1..2), (5, 2..3), (3, 3..4), (2, 4..6), (1, 6..11)]
+/// );
+///
+/// // Σ_{i=1}^{n} ⌊n / i⌋
+/// let sum = quotient_blocks(1000000).map(|(q, r)| q * (r.end - r.start)).sum::<u64>();
+/// assert_eq!(sum, 13970034);
+/// ```
+pub fn quotient_blocks(n: u64) -> QuotientBlocks {
+    assert!(n < u64::MAX, "the last range would end at 2^64");
+    QuotientBlocks { n, i: 1 }
+}
+
+#[test]
+fn floor_sum_test() {
+    use rand::Rng;
+
+    let naive =
+        |n: i64, m: i64, a: i64, b: i64| (0..n).map(|i| (a * i + b).div_euclid(m)).sum::<i64>();
+
+    for n in 0..20 {
+        for m in 1..20 {
+            for a in -20..20 {
+                for b in -20..20 {
+                    assert_eq!(floor_sum(n, m, a, b), naive(n, m, a, b));
+                }
+            }
+        }
+    }
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let n = rng.gen_range(0..10000);
+        let m = rng.gen_range(1..1 << 32);
+        let a = rng.gen_range(-(1 << 32)..1 << 32);
+        let b = rng.gen_range(-(1 << 32)..1 << 32);
+        assert_eq!(floor_sum(n, m, a, b), naive(n, m, a, b));
+    }
+}
+
+#[test]
+fn quotient_blocks_test() {
+    for n in 0..1000 {
+        let mut i = 1;
+        for (q, range) in quotient_blocks(n) {
+            assert_eq!(range.start, i);
+            assert!(range.start < range.end);
+            assert!(range.clone().all(|i| n / i == q));
+            i = range.end;
+        }
+        assert_eq!(i, n + 1);
+        assert!(quotient_blocks(n).count() as u64 <= 2 * n.isqrt());
+    }
+
+    // the largest `n`, whose last range ends at `u64::MAX`
+    let n = u64::MAX - 1;
+    assert_eq!(quotient_blocks(n).next(), Some((n, 1..2)));
+    let mut last = QuotientBlocks { n, i: n / 2 + 1 };
+    assert_eq!(last.next(), Some((1, n / 2 + 1..u64::MAX)));
+    assert_eq!(last.next(), None);
+    assert!(std::panic::catch_unwind(|| quotient_blocks(u64::MAX)).is_err());
+}
@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 
 pub mod atkin;
 pub mod eratosthenes;
+pub mod linear;
+pub mod segmented;
 
 pub trait PrimeSieve {
     fn limit(&self) -> usize;
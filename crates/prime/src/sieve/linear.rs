@@ -0,0 +1,244 @@
+use super::PrimeSieve;
+use crate::factorize::{FactorizationError, PrimeFactors};
+use std::collections::BTreeMap;
+
+/// # Linear sieve
+///
+/// See https://cp-algorithms.com/algebra/prime-sieve-linear.html
+///
+/// Marks each composite number exactly once by its smallest prime factor,
+/// and computes Euler's totient, the Möbius function,
+/// the number of divisors and the sum of divisors in the same pass.
+///
+/// Complexity: `O(n)`
+///
+/// ```
+/// use prime::sieve::{linear::LinearSieve, PrimeSieve};
+///
+/// let sieve = LinearSieve::new(100);
+/// assert_eq!(sieve.smallest_prime_factor(91), 7);
+/// assert_eq!(sieve.phi(36), 12);
+/// assert_eq!(sieve.mu(30), -1);
+/// assert_eq!(sieve.divisor_count(36), 9);
+/// assert_eq!(sieve.divisor_sum(36), 91);
+/// assert_eq!(
+///     sieve.factorize(72).unwrap().prime_factors().into_iter().collect::<Vec<_>>(),
+///     vec![(2, 3), (3, 2)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearSieve {
+    limit: usize,
+    smallest_prime_factor: Box<[usize]>,
+    primes: Box<[usize]>,
+    phi: Box<[usize]>,
+    mu: Box<[i8]>,
+    divisor_count: Box<[usize]>,
+    divisor_sum: Box<[usize]>,
+}
+
+impl LinearSieve {
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn new(limit: usize) -> Self {
+        let len = limit.max(1) + 1;
+        let mut smallest_prime_factor = vec![0; len];
+        let mut phi = vec![0; len];
+        let mut mu = vec![0; len];
+        let mut divisor_count = vec![0; len];
+        let mut divisor_sum = vec![0; len];
+        // n divided by the largest power of its smallest prime factor
+        let mut rest = vec![0; len];
+
+        smallest_prime_factor[1] = 1;
+        phi[1] = 1;
+        mu[1] = 1;
+        divisor_count[1] = 1;
+        divisor_sum[1] = 1;
+        rest[1] = 1;
+
+        let mut primes = vec![];
+        for i in 2..=limit {
+            if smallest_prime_factor[i] == 0 {
+                smallest_prime_factor[i] = i;
+                primes.push(i);
+                phi[i] = i - 1;
+                mu[i] = -1;
+                divisor_count[i] = 2;
+                divisor_sum[i] = i + 1;
+                rest[i] = 1;
+            }
+
+            for &p in primes.iter() {
+                let j = i * p;
+                if p > smallest_prime_factor[i] || j > limit {
+                    break;
+                }
+
+                smallest_prime_factor[j] = p;
+                if p == smallest_prime_factor[i] {
+                    let r = rest[i];
+                    rest[j] = r;
+                    phi[j] = phi[i] * p;
+                    mu[j] = 0;
+                    // d(p^(e+1)) = d(p^e) + 1, σ(p^(e+1)) = σ(p^e) p + 1
+                    divisor_count[j] = divisor_count[i] + divisor_count[r];
+                    divisor_sum[j] = divisor_sum[i] * p + divisor_sum[r];
+                } else {
+                    rest[j] = i;
+                    phi[j] = phi[i] * (p - 1);
+                    mu[j] = -mu[i];
+                    divisor_count[j] = divisor_count[i] * 2;
+                    divisor_sum[j] = divisor_sum[i] * (p + 1);
+                }
+            }
+        }
+
+        Self {
+            limit,
+            smallest_prime_factor: smallest_prime_factor.into_boxed_slice(),
+            primes: primes.into_boxed_slice(),
+            phi: phi.into_boxed_slice(),
+            mu: mu.into_boxed_slice(),
+            divisor_count: divisor_count.into_boxed_slice(),
+            divisor_sum: divisor_sum.into_boxed_slice(),
+        }
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.smallest_prime_factor[n] == n
+    }
+
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+
+    /// Returns the smallest prime factor of `n ≥ 2`
+    pub fn smallest_prime_factor(&self, n: usize) -> usize {
+        self.smallest_prime_factor[n]
+    }
+
+    /// Returns Euler's totient `φ(n)`
+    pub fn phi(&self, n: usize) -> usize {
+        self.phi[n]
+    }
+
+    /// Returns the Möbius function `μ(n)`
+    pub fn mu(&self, n: usize) -> i8 {
+        self.mu[n]
+    }
+
+    /// Returns the number of divisors `d(n)`
+    pub fn divisor_count(&self, n: usize) -> usize {
+        self.divisor_count[n]
+    }
+
+    /// Returns the sum of divisors `σ(n)`
+    pub fn divisor_sum(&self, n: usize) -> usize {
+        self.divisor_sum[n]
+    }
+}
+
+impl PrimeSieve for LinearSieve {
+    fn limit(&self) -> usize {
+        self.limit()
+    }
+
+    fn is_prime(&self, n: usize) -> bool {
+        self.is_prime(n)
+    }
+
+    fn primes(&self) -> &[usize] {
+        self.primes()
+    }
+
+    /// Factorizes `n` in `O(log n)` if `n ≤ limit`,
+    /// or by trial division with the primes otherwise
+    fn factorize(&self, mut n: usize) -> Result<PrimeFactors<usize>, FactorizationError> {
+        assert!(n <= self.limit.saturating_mul(self.limit));
+        if n == 0 {
+            return Err(FactorizationError::Zero);
+        } else if n == 1 {
+            return Err(FactorizationError::One);
+        }
+
+        let mut factors = BTreeMap::new();
+        for &p in self.primes.iter() {
+            if n <= self.limit || p * p > n {
+                break;
+            }
+            while n.is_multiple_of(p) {
+                n /= p;
+                *factors.entry(p).or_insert(0) += 1;
+            }
+        }
+
+        if n > self.limit {
+            factors.insert(n, 1);
+        } else {
+            while n != 1 {
+                let p = self.smallest_prime_factor[n];
+                n /= p;
+                *factors.entry(p).or_insert(0) += 1;
+            }
+        }
+
+        Ok(PrimeFactors(factors))
+    }
+}
+
+#[test]
+fn linear_sieve_test() {
+    use super::eratosthenes::SieveOfEratosthenes;
+
+    const N: usize = 2000;
+    let sieve = LinearSieve::new(N);
+    let eratosthenes = SieveOfEratosthenes::new(N);
+    assert_eq!(sieve.primes(), eratosthenes.primes());
+
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    for n in 1..=N {
+        assert_eq!(sieve.is_prime(n), eratosthenes.is_prime(n));
+
+        let divisors = (1..=n).filter(|d| n.is_multiple_of(*d)).collect::<Vec<_>>();
+        assert_eq!(sieve.divisor_count(n), divisors.len());
+        assert_eq!(sieve.divisor_sum(n), divisors.iter().sum::<usize>());
+        assert_eq!(sieve.phi(n), (1..=n).filter(|&k| gcd(n, k) == 1).count());
+
+        let mu = if n == 1 {
+            1
+        } else {
+            let factors = PrimeSieve::factorize(&sieve, n).unwrap().prime_factors();
+            assert_eq!(
+                factors,
+                PrimeSieve::factorize(&eratosthenes, n)
+                    .unwrap()
+                    .prime_factors()
+            );
+            if factors.values().any(|&c| c > 1) {
+                0
+            } else if factors.len() % 2 == 0 {
+                1
+            } else {
+                -1
+            }
+        };
+        assert_eq!(sieve.mu(n), mu);
+    }
+
+    for n in [N * N, N * N - 1, 1999 * 1997, 1999 * 2 * 2 * 3] {
+        assert_eq!(
+            PrimeSieve::factorize(&sieve, n).unwrap().prime_factors(),
+            PrimeSieve::factorize(&eratosthenes, n)
+                .unwrap()
+                .prime_factors()
+        );
+    }
+}
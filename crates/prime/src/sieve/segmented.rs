@@ -0,0 +1,167 @@
+use super::eratosthenes::SieveOfEratosthenes;
+use crate::factorize::{FactorizationError, PrimeFactors};
+use std::collections::BTreeMap;
+
+/// # Segmented sieve
+///
+/// See https://cp-algorithms.com/algebra/sieve-of-eratosthenes.html#segmented-sieve
+///
+/// Finds primes in an interval `[left, right]`,
+/// sieving only by the base primes up to `√right`.
+/// Memory usage is `O(√right + (right - left))`,
+/// so the interval may lie far beyond what [`SieveOfEratosthenes`] can allocate.
+///
+/// Complexity: `O(√right log log right + (right - left) log log right)`
+///
+/// ```
+/// use prime::sieve::segmented::SegmentedSieve;
+///
+/// let sieve = SegmentedSieve::new(1_000_000_000_000, 1_000_000_000_100);
+/// assert_eq!(sieve.primes(), &[1_000_000_000_039, 1_000_000_000_061, 1_000_000_000_063, 1_000_000_000_091]);
+/// assert!(sieve.is_prime(1_000_000_000_039));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentedSieve {
+    left: usize,
+    right: usize,
+    base: SieveOfEratosthenes,
+    is_prime: Box<[bool]>,
+    primes: Box<[usize]>,
+}
+
+impl SegmentedSieve {
+    pub fn left(&self) -> usize {
+        self.left
+    }
+
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    /// Sieves the interval `[left, right]`
+    pub fn new(left: usize, right: usize) -> Self {
+        assert!(left <= right);
+
+        let base = SieveOfEratosthenes::new(right.isqrt().max(1));
+        let mut is_prime = vec![true; right - left + 1];
+        for n in left..2.min(right + 1) {
+            is_prime[n - left] = false;
+        }
+
+        for &p in base.primes() {
+            let start = (p * p).max(left.div_ceil(p) * p);
+            for m in (start..=right).step_by(p) {
+                is_prime[m - left] = false;
+            }
+        }
+
+        let primes = is_prime
+            .iter()
+            .enumerate()
+            .filter(|&(_, &e)| e)
+            .map(|(i, _)| left + i)
+            .collect::<Vec<_>>();
+
+        Self {
+            left,
+            right,
+            base,
+            is_prime: is_prime.into_boxed_slice(),
+            primes: primes.into_boxed_slice(),
+        }
+    }
+
+    /// Returns whether `n` is a prime number, for `left ≤ n ≤ right`
+    pub fn is_prime(&self, n: usize) -> bool {
+        assert!(self.left <= n && n <= self.right);
+        self.is_prime[n - self.left]
+    }
+
+    /// Returns the primes in `[left, right]`
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+
+    /// Factorizes every integer in `[left, right]`, in ascending order
+    ///
+    /// Complexity: `O(√right / log right + (right - left) log log right)`
+    ///
+    /// ```
+    /// use prime::sieve::segmented::SegmentedSieve;
+    ///
+    /// let sieve = SegmentedSieve::new(1, 4);
+    /// let factors = sieve
+    ///     .factorize_all()
+    ///     .into_iter()
+    ///     .map(|f| f.ok().map(|f| f.prime_factors().into_iter().collect::<Vec<_>>()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(factors, vec![None, Some(vec![(2, 1)]), Some(vec![(3, 1)]), Some(vec![(2, 2)])]);
+    /// ```
+    pub fn factorize_all(&self) -> Vec<Result<PrimeFactors<usize>, FactorizationError>> {
+        let mut rest = (self.left..=self.right).collect::<Vec<_>>();
+        let mut factors = vec![BTreeMap::new(); rest.len()];
+
+        for &p in self.base.primes() {
+            let start = p.max(self.left.div_ceil(p) * p);
+            for m in (start..=self.right).step_by(p) {
+                let i = m - self.left;
+                let mut c = 0;
+                while rest[i].is_multiple_of(p) {
+                    rest[i] /= p;
+                    c += 1;
+                }
+                factors[i].insert(p, c);
+            }
+        }
+
+        rest.into_iter()
+            .zip(factors)
+            .enumerate()
+            .map(|(i, (r, mut f))| match self.left + i {
+                0 => Err(FactorizationError::Zero),
+                1 => Err(FactorizationError::One),
+                _ => {
+                    if r > 1 {
+                        f.insert(r, 1);
+                    }
+                    Ok(PrimeFactors(f))
+                }
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn segmented_sieve_test() {
+    use crate::factorize::Factorization;
+    use crate::miller_rabin::is_prime;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let eratosthenes = SieveOfEratosthenes::new(10000);
+    for _ in 0..100 {
+        let left = rng.gen_range(0..10000);
+        let right = rng.gen_range(left..=10000);
+        let sieve = SegmentedSieve::new(left, right);
+        for n in left..=right {
+            assert_eq!(sieve.is_prime(n), eratosthenes.is_prime(n), "{}", n);
+        }
+    }
+
+    let left = 1_000_000_000_000 - 5000;
+    let right = 1_000_000_000_000 + 5000;
+    let sieve = SegmentedSieve::new(left, right);
+    for n in left..=right {
+        assert_eq!(sieve.is_prime(n), is_prime(n as u64), "{}", n);
+    }
+    for (n, factors) in (left..=right).zip(sieve.factorize_all()) {
+        assert_eq!(
+            factors.unwrap().prime_factors(),
+            n.factorize().unwrap().prime_factors()
+        );
+    }
+
+    let factors = SegmentedSieve::new(0, 1).factorize_all();
+    assert!(matches!(factors[0], Err(FactorizationError::Zero)));
+    assert!(matches!(factors[1], Err(FactorizationError::One)));
+}
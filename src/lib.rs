@@ -39,6 +39,7 @@ pub use bitset;
 pub use fenwick_tree;
 pub use graph;
 pub use io;
+pub use matrix;
 pub use modint;
 pub use prime;
 pub use rolling_hash;